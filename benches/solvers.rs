@@ -0,0 +1,54 @@
+/*!
+Times every method of the command-line interface on every test problem, to each of `bench::TOLERANCES`, with
+`criterion`. Run with `cargo bench`, or e.g. `cargo bench -- trig/` for a single problem.
+
+Each problem is a benchmark group, and each method and tolerance a benchmark within it. Cases in which the method
+fails to find a root are skipped, so as not to time failures alongside successes; `root-finder bench` lists them.
+*/
+
+extern crate clap;
+extern crate criterion;
+extern crate root_finding;
+
+use clap::ValueEnum;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use root_finding::bench::{self, TOLERANCES};
+use root_finding::cli::{self, Function, Method};
+use std::hint::black_box;
+use std::time::Duration;
+
+/// The name by which the command-line interface refers to a value of a `clap::ValueEnum`.
+fn name<E: ValueEnum>(value: &E) -> String {
+    value
+        .to_possible_value()
+        .map_or_else(String::new, |v| v.get_name().to_string())
+}
+
+fn solvers(c: &mut Criterion) {
+    for function in Function::value_variants() {
+        let mut group = c.benchmark_group(name(function));
+        for method in Method::value_variants() {
+            for &tol in &TOLERANCES {
+                let case = bench::case(*function, *method, tol);
+                if cli::solve(&case).is_err() {
+                    continue;
+                }
+                group.bench_with_input(
+                    BenchmarkId::new(name(method), format!("{:e}", tol)),
+                    &case,
+                    |b, case| b.iter(|| cli::solve(black_box(case))),
+                );
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .warm_up_time(Duration::from_millis(200))
+        .measurement_time(Duration::from_secs(1));
+    targets = solvers
+}
+criterion_main!(benches);
@@ -0,0 +1,137 @@
+#ifndef ROOT_FINDING_H
+#define ROOT_FINDING_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stddef.h>
+
+/**
+ * Whether a search succeeded, and if not why it stopped, as in `root_search::TerminationReason`.
+ */
+typedef enum RfStatus {
+  /**
+   * The search found a root.
+   */
+  RF_STATUS_OK = 0,
+  /**
+   * A function pointer or the out-pointer was null.
+   */
+  RF_STATUS_NULL_POINTER = 1,
+  /**
+   * The function doesn't change sign over the interval given to `rf_bisect`.
+   */
+  RF_STATUS_INVALID_BRACKET = 2,
+  /**
+   * The maximum number of iterations was reached.
+   */
+  RF_STATUS_MAX_ITERATIONS = 3,
+  /**
+   * The iterates were growing without bound.
+   */
+  RF_STATUS_DIVERGED = 4,
+  /**
+   * The iterates entered a cycle.
+   */
+  RF_STATUS_CYCLING = 5,
+  /**
+   * The function evaluated to NaN or infinity, at `RfResult::root`.
+   */
+  RF_STATUS_NON_FINITE = 6,
+  /**
+   * The budget of function evaluations or time was spent.
+   */
+  RF_STATUS_BUDGET_EXHAUSTED = 7,
+} RfStatus;
+
+/**
+ * A C function `double f(double x, void *ctx)`, called with the context passed to the solver.
+ */
+typedef double (*RfFunction)(double x, void *ctx);
+
+/**
+ * The outcome of a search.
+ *
+ * On success, `root` is the root found; on failure, it is the last iterate computed, or NaN if there were none. The
+ * iterates themselves aren't returned, to keep the struct free of allocations the caller would have to release.
+ */
+typedef struct RfResult {
+  double root;
+  /**
+   * The number of iterations made, each evaluating the function at one iterate; for `rf_bisect`, at one midpoint.
+   */
+  size_t iterations;
+  /**
+   * The number of function evaluations made, including evaluations of the derivative.
+   */
+  size_t evaluations;
+} RfResult;
+
+/**
+ * The final bracket of `rf_bisect`, which contains the root.
+ */
+typedef struct RfBracket {
+  double lo;
+  double hi;
+} RfBracket;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Binary search for a root of `func` over `[a, b]`, to within `tol`, making at most `max_iter` iterations.
+ *
+ * On success, the final bracket is also written to `bracket`, unless it is null.
+ *
+ * Safety
+ * ------
+ * `func` must be safe to call with `ctx`, and `bracket` and `out` must be null or valid for writes.
+ */
+enum RfStatus rf_bisect(RfFunction func,
+                        void *ctx,
+                        double a,
+                        double b,
+                        double tol,
+                        size_t max_iter,
+                        struct RfBracket *bracket,
+                        struct RfResult *out);
+
+/**
+ * Newton-Raphson iteration on `func`, with derivative `deriv`, from `x0` until successive iterates are within `tol`.
+ *
+ * Safety
+ * ------
+ * `func` and `deriv` must be safe to call with `ctx`, and `out` must be null or valid for writes.
+ */
+enum RfStatus rf_newton(RfFunction func,
+                        RfFunction deriv,
+                        void *ctx,
+                        double x0,
+                        double tol,
+                        size_t max_iter,
+                        struct RfResult *out);
+
+/**
+ * Fixed point iteration of `func` from `x0` until successive iterates are within `tol`.
+ *
+ * Safety
+ * ------
+ * `func` must be safe to call with `ctx`, and `out` must be null or valid for writes.
+ */
+enum RfStatus rf_fixed_point(RfFunction func,
+                             void *ctx,
+                             double x0,
+                             double tol,
+                             size_t max_iter,
+                             struct RfResult *out);
+
+/**
+ * A description of `status`, as a static NUL-terminated string, which the caller must not free.
+ */
+const char *rf_status_message(enum RfStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ROOT_FINDING_H */
@@ -0,0 +1,404 @@
+/*!
+Tools for analysing the convergence of root-finding algorithms.
+
+Functions
+---------
+* `estimate_order` : Estimates the order of convergence and asymptotic error constant from a sequence of iterates.
+* `basins` : Runs a root-finder from each of a set of starting points, recording which root each converges to.
+* `newton_basins` : Runs complex Newton-Raphson on a polynomial over a grid of starting points in the complex plane.
+* `sensitivity` : Estimates the condition number of a root, and bounds the error in an approximation to it.
+
+Types
+-----
+* `ConvergenceReport` : The errors, error ratios and order estimates of a sequence of iterates.
+* `Basin` : Which root a run converged to, and how many iterations it took.
+* `NewtonFractal` : The basins of attraction of complex Newton-Raphson over a grid.
+* `Sensitivity` : The condition number of a root, and an error bound for an approximation to it.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use num::Complex;
+use polynomial::{self, Polynomial};
+use root_search::{RootError, RootResult};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use BatchedFunction;
+use Float;
+use ObjectiveFn;
+
+/**
+The empirical convergence behaviour of a sequence of iterates `x_N` approaching a known root `x_*`.
+
+If `|e_{N+1}| ~ C|e_N|^p`, where `e_N = x_N - x_*`, then `p` is the order of convergence and `C` the asymptotic error
+constant. The per-iterate vectors are aligned with the iterates, with `None` where an estimate needs earlier iterates
+than exist, or is undefined because an error is exactly zero.
+*/
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConvergenceReport<T = f64> {
+    /// The signed errors `e_N = x_N - x_*`.
+    pub errors: Vec<T>,
+    /// The error ratios `e_N/e_{N-1}`.
+    pub ratios: Vec<Option<T>>,
+    /// The order estimates `ln|e_N/e_{N-1}| / ln|e_{N-1}/e_{N-2}|`.
+    pub orders: Vec<Option<T>>,
+    /// The estimated order of convergence `p`.
+    pub order: Option<T>,
+    /// The estimated asymptotic error constant `C`.
+    pub error_constant: Option<T>,
+}
+
+/**
+Estimates the order of convergence and asymptotic error constant of a sequence of iterates.
+
+Taking logarithms of `|e_{N+1}| = C|e_N|^p` at consecutive `N` and eliminating `C` gives
+`p = ln|e_{N+1}/e_N| / ln|e_N/e_{N-1}|`, and then `C = |e_{N+1}|/|e_N|^p`. The overall estimates are taken from the
+last iterates whose errors are still well above rounding error in `x_*`, since the final iterates of a fast method are
+typically dominated by rounding.
+
+Parameters
+----------
+* `history` : The iterates `x_0, x_1, ...`.
+* `root` : The true root `x_*`, or an accurate approximation to it.
+
+Returns
+-------
+* `ConvergenceReport<T>` : The errors, ratios and order estimates of the iterates.
+
+Examples
+--------
+In this example, `analysis::estimate_order` should find that Newton-Raphson converges quadratically to the root of `trig`.
+```rust
+# use root_finding::*;
+let newton = functional::x_minus(functional::newton_raphson(&test_function::trig, &test_func_derivative::trig));
+let res = root_search::fixed_point(&newton, -4.0, 1e-15, root_search::Budget::new(100, None))?;
+let report = analysis::estimate_order(&res.history, res.root);
+assert_eq!(Some(2.0), report.order.map(|p| p.round()));
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn estimate_order<T: Float>(history: &[T], root: T) -> ConvergenceReport<T> {
+    let errors: Vec<T> = history.iter().map(|&x| x - root).collect();
+    // Errors this small are dominated by the rounding error in `root`, so say nothing about convergence.
+    let noise_floor: T = T::from(100.0).unwrap() * T::epsilon() * root.abs().max(T::one());
+
+    let ratio = |n: usize| -> Option<T> {
+        if n == 0 || errors[n - 1].is_zero() {
+            None
+        } else {
+            Some(errors[n] / errors[n - 1])
+        }
+    };
+    let ratios: Vec<Option<T>> = (0..errors.len()).map(ratio).collect();
+    let orders: Vec<Option<T>> = (0..errors.len())
+        .map(|n| {
+            let prev_ratio: Option<T> = if n > 0 { ratios[n - 1] } else { None };
+            match (ratios[n], prev_ratio) {
+                (Some(r), Some(prev_r)) if !r.is_zero() && prev_r.abs() != T::one() => {
+                    Some(r.abs().ln() / prev_r.abs().ln())
+                }
+                _ => None,
+            }
+        })
+        .collect();
+
+    let last: Option<usize> = (0..errors.len())
+        .rev()
+        .find(|&n| orders[n].is_some() && errors[n].abs() > noise_floor);
+    let order: Option<T> = last.and_then(|n| orders[n]);
+    let error_constant: Option<T> =
+        last.and_then(|n| order.map(|p| errors[n].abs() / errors[n - 1].abs().powf(p)));
+
+    ConvergenceReport {
+        errors,
+        ratios,
+        orders,
+        order,
+        error_constant,
+    }
+}
+
+/// The outcome of a root search from one starting point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Basin {
+    /// The index of the root converged to, or `None` if the search failed or converged to none of the known roots.
+    pub root: Option<usize>,
+    /// The number of iterations taken.
+    pub iterations: usize,
+}
+
+/// The basins of attraction of complex Newton-Raphson on a polynomial, over a grid of starting points.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NewtonFractal {
+    /// The roots of the polynomial.
+    pub roots: Vec<Complex<f64>>,
+    /// The outcome from each grid point, indexed as `[row][column]` with row 0 at the top (largest imaginary part).
+    /// `Basin::root` indexes into `roots`.
+    pub basins: Vec<Vec<Basin>>,
+}
+
+/// The index of the nearest root, given the distance to each, if it is within `tol`; NaN distances are never nearest.
+fn nearest_root<T: Float, I: IntoIterator<Item = T>>(distances: I, tol: T) -> Option<usize> {
+    distances
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, dist)| dist < tol)
+        .fold(
+            None,
+            |nearest: Option<(usize, T)>, (i, dist)| match nearest {
+                Some((_, min)) if min <= dist => nearest,
+                _ => Some((i, dist)),
+            },
+        )
+        .map(|(i, _)| i)
+}
+
+/**
+Runs a root-finder from each of a set of starting points, recording which of the known roots each run converges to.
+
+Plotting the result against the starting points shows the basins of attraction of each root, and so how sensitive a
+method is to the initial guess.
+
+Parameters
+----------
+* `solver` : A root-finder taking a starting point, with the same return type as `root_search::fixed_point`.
+* `starts` : The starting points.
+* `roots` : The known roots.
+* `tol` : How close a run's result must be to a known root to count as converging to it.
+
+Returns
+-------
+* `Vec<Basin>` : The outcome of the run from each starting point, in order.
+
+Examples
+--------
+In this example, we find which starting points in [0, 6] lead Newton-Raphson to each root of `polynom`.
+```rust
+# use root_finding::*;
+let newton = functional::x_minus(functional::newton_raphson(&test_function::polynom, &test_func_derivative::polynom));
+let solver = |x0: f64| root_search::fixed_point(&newton, x0, 1e-10, root_search::Budget::new(100, None));
+let starts: Vec<f64> = (0..=60).map(|i| i as f64 / 10.0).collect();
+let basins = analysis::basins(&solver, &starts, &[0.5, 4.0], 1e-4);
+assert_eq!(Some(0), basins[0].root);
+```
+---
+In this example, a solver which reports a NaN root from some starting points is run, and those runs are unlabelled.
+```rust
+# use root_finding::*;
+use root_finding::root_search::RootResult;
+let solver = |x0: f64| {
+    let root: f64 = if x0 < 0.0 { f64::NAN } else { 1.0 };
+    Ok(RootResult { root, history: vec![x0], residuals: vec![0.0], evaluations: 1 })
+};
+let basins = analysis::basins(&solver, &[-1.0, 2.0], &[1.0, 3.0], 1e-6);
+assert_eq!(vec![None, Some(0)], basins.iter().map(|basin| basin.root).collect::<Vec<_>>());
+```
+*/
+pub fn basins<T: Float>(
+    solver: &dyn Fn(T) -> Result<RootResult<T>, RootError<T>>,
+    starts: &[T],
+    roots: &[T],
+    tol: T,
+) -> Vec<Basin> {
+    starts
+        .iter()
+        .map(|&x0| match solver(x0) {
+            Ok(res) => Basin {
+                root: nearest_root(roots.iter().map(|&r| (res.root - r).abs()), tol),
+                iterations: res.history.len(),
+            },
+            Err(err) => Basin {
+                root: None,
+                iterations: err.history.len(),
+            },
+        })
+        .collect()
+}
+
+/**
+Runs Newton-Raphson `z -> z - p(z)/p'(z)` on a polynomial from every point of a grid in the complex plane, the
+classic Newton fractal.
+
+The roots are found first with `polynomial::durand_kerner`, and each run is labelled with the index of the root it
+converges to. A polynomial `p` of degree `n` has a root within `n|p(z)/p'(z)|` of any `z`, so a run whose last step was
+below `trunc_err` ends within `(n+1)·trunc_err` of a root; it is labelled with the nearest root found only if it is
+within twice this of it, allowing for the error in the roots themselves, and is otherwise left unlabelled. A run whose
+step is NaN or infinite, e.g. at a critical point of the polynomial, stops unlabelled at that iteration. All the runs
+are stepped together, evaluating the polynomial and its derivative at every point still iterating in one call to
+`BatchedFunction::eval_many` per step.
+
+Parameters
+----------
+* `poly` : A polynomial of degree at least 1.
+* `re` : The range of real parts of the grid.
+* `im` : The range of imaginary parts of the grid.
+* `resolution` : The number of grid points `(columns, rows)`.
+* `trunc_err` : A float representing the acceptable truncation error.
+* `max_iter` : The maximum number of iterations from each starting point.
+
+Returns
+-------
+* `NewtonFractal` : The roots, and the outcome from each grid point.
+
+Errors
+------
+* `&'static str` : If the roots of `poly` can't be found.
+
+Examples
+--------
+In this example, we compute the Newton fractal of `z^3 - 1`, and check that the start 1 converges to the root 1.
+```rust
+# use root_finding::*;
+let poly = polynomial::Polynomial::new(vec![-1.0, 0.0, 0.0, 1.0]);
+let fractal = analysis::newton_basins(&poly, (-1.0, 1.0), (-1.0, 1.0), (201, 201), 1e-10, 100)?;
+let i: usize = fractal.basins[100][200].root.unwrap();
+assert_eq!(1.0, (fractal.roots[i].re*10.0).round()/10.0);
+// The start 0 is a critical point of `z^3 - 1`, so the first step is infinite.
+assert_eq!(analysis::Basin { root: None, iterations: 1 }, fractal.basins[100][100]);
+# Ok::<(), &'static str>(())
+```
+*/
+pub fn newton_basins(
+    poly: &Polynomial,
+    re: (f64, f64),
+    im: (f64, f64),
+    resolution: (usize, usize),
+    trunc_err: f64,
+    max_iter: usize,
+) -> Result<NewtonFractal, &'static str> {
+    let roots: Vec<Complex<f64>> = polynomial::durand_kerner(poly, trunc_err, max_iter)
+        .map_err(|_| "Error: could not find the roots of the polynomial!")?;
+    let deriv: Polynomial = poly.derivative();
+    let step = |lo: f64, hi: f64, n: usize| -> f64 {
+        if n > 1 {
+            (hi - lo) / (n - 1) as f64
+        } else {
+            0.0
+        }
+    };
+    let (re_step, im_step): (f64, f64) = (
+        step(re.0, re.1, resolution.0),
+        step(im.0, im.1, resolution.1),
+    );
+
+    let tol: f64 = 2.0 * (roots.len() + 1) as f64 * trunc_err;
+    let nearest = |z: Complex<f64>| -> Option<usize> {
+        nearest_root(roots.iter().map(|&r| (z - r).norm()), tol)
+    };
+
+    let (columns, rows): (usize, usize) = resolution;
+    let mut points: Vec<Complex<f64>> = (0..rows)
+        .flat_map(|row| {
+            let y: f64 = im.1 - row as f64 * im_step;
+            (0..columns).map(move |col| Complex::new(re.0 + col as f64 * re_step, y))
+        })
+        .collect();
+    let mut outcomes: Vec<Basin> = vec![
+        Basin {
+            root: None,
+            iterations: max_iter,
+        };
+        points.len()
+    ];
+    // Every point still iterating is stepped at once, evaluating `poly` and `deriv` at all of them in one call each.
+    let mut active: Vec<usize> = (0..points.len()).collect();
+    let mut zs: Vec<Complex<f64>> = Vec::new();
+    let (mut p_vals, mut d_vals): (Vec<Complex<f64>>, Vec<Complex<f64>>) = (Vec::new(), Vec::new());
+    for n in 1..=max_iter {
+        if active.is_empty() {
+            break;
+        }
+        zs.clear();
+        zs.extend(active.iter().map(|&i| points[i]));
+        p_vals.resize(zs.len(), Complex::new(0.0, 0.0));
+        d_vals.resize(zs.len(), Complex::new(0.0, 0.0));
+        poly.eval_many(&zs, &mut p_vals);
+        deriv.eval_many(&zs, &mut d_vals);
+
+        let mut still_active: Vec<usize> = Vec::new();
+        for (k, &i) in active.iter().enumerate() {
+            let dz: Complex<f64> = p_vals[k] / d_vals[k];
+            if !dz.is_finite() {
+                outcomes[i] = Basin {
+                    root: None,
+                    iterations: n,
+                };
+                continue;
+            }
+            points[i] -= dz;
+            if dz.norm() < trunc_err {
+                outcomes[i] = Basin {
+                    root: nearest(points[i]),
+                    iterations: n,
+                };
+            } else {
+                still_active.push(i);
+            }
+        }
+        active = still_active;
+    }
+
+    let basins: Vec<Vec<Basin>> = (0..rows)
+        .map(|row| outcomes[row * columns..(row + 1) * columns].to_vec())
+        .collect();
+    Ok(NewtonFractal { roots, basins })
+}
+
+/// How sensitive a root is to perturbations of the function, and how far an approximation to it may be from the root.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sensitivity<T = f64> {
+    /// The absolute condition number `1/|F'(x)|`: a perturbation `δ` of `F` moves the root by about `δ/|F'(x)|`.
+    pub condition: T,
+    /// The residual `|F(x)|`.
+    pub residual: T,
+    /// The forward error bound `|F(x)|/|F'(x)|` on the distance from `x` to the root.
+    pub error_bound: T,
+}
+
+/**
+Estimates the condition number of a root, and bounds the error in an approximation `x` to it.
+
+By the mean value theorem, if `F` has a root `r` then `F(x) = F'(ξ)(x - r)` for some `ξ` between them, so
+`|x - r| ≈ |F(x)|/|F'(x)|` when `F'` varies little between `x` and `r`. Unlike the truncation error of the search,
+this accounts for the conditioning of the root: where `|F'|` is small, a tiny residual still allows a large error, and
+rounding error in evaluating `F` alone puts a floor of about `ε|F|/|F'|` under the achievable accuracy. At a multiple
+root `F'(r) = 0`, so the condition number and bound are infinite, or meaningless close to the root.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `deriv` : The derivative of `func`.
+* `root` : An approximation to a root of `func`.
+
+Returns
+-------
+* `Sensitivity<T>` : The condition number, residual and error bound at `root`.
+
+Examples
+--------
+In this example, `analysis::sensitivity` shows that the simple root of `polynom` at 0.5 is well-conditioned, and that
+an estimate 1e-3 from it has an error bound close to 1e-3.
+```rust
+# use root_finding::*;
+let res = analysis::sensitivity(&test_function::polynom, &test_func_derivative::polynom, 0.501);
+assert!(res.condition < 0.1);
+assert!((res.error_bound - 1e-3).abs() < 1e-5);
+```
+*/
+pub fn sensitivity<T: Float, F: ObjectiveFn<T> + ?Sized, D: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    deriv: &D,
+    root: T,
+) -> Sensitivity<T> {
+    let condition: T = T::one() / deriv.eval(root).abs();
+    let residual: T = func.eval(root).abs();
+    Sensitivity {
+        condition,
+        residual,
+        error_bound: residual * condition,
+    }
+}
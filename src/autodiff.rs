@@ -0,0 +1,433 @@
+/*!
+Forward-mode automatic differentiation with dual numbers.
+
+A dual number `a + bε`, where `ε² = 0`, carries a value `a` together with a derivative `b`. Evaluating a function at
+`x + ε` yields `f(x) + f'(x)ε`, so the derivative comes for free, exact to rounding error. This removes the need to
+derive and maintain functions like those in `test_func_derivative` by hand.
+
+`Dual` implements `num::Float`, so any function written generically (such as those in `test_function::generic`) can be
+differentiated automatically.
+
+Types
+-----
+* `Dual` : A dual number over `f64`.
+
+Functions
+---------
+* `derivative` : Evaluates the derivative of a function of a dual number.
+*/
+
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use num::traits::{Num, NumCast, One, ToPrimitive, Zero};
+use Float;
+
+/**
+A dual number `val + deriv·ε`.
+
+Comparisons only consider `val`, so that branching code (e.g. `abs`, `max`) takes the same branch as it would on the
+underlying `f64`.
+
+Examples
+--------
+In this example, we differentiate f(x) = x·sin(x) at x=pi, where f'(pi) = -pi.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use num::Float;
+# use root_finding::*;
+let x = autodiff::Dual::variable(3.1415);
+let res = x * x.sin();
+assert_eq!(-3.1, (res.deriv*10.0).round()/10.0);
+```
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dual {
+    /// The value of the function.
+    pub val: f64,
+    /// The derivative of the function.
+    pub deriv: f64,
+}
+
+impl Dual {
+    pub fn new(val: f64, deriv: f64) -> Dual {
+        Dual { val, deriv }
+    }
+
+    /// The independent variable at `x`, i.e. `x + ε`.
+    pub fn variable(x: f64) -> Dual {
+        Dual::new(x, 1.0)
+    }
+
+    /// A constant, i.e. `c + 0ε`.
+    pub fn constant(c: f64) -> Dual {
+        Dual::new(c, 0.0)
+    }
+
+    /// Applies a function `f` with known derivative `df` to `self`, by the chain rule.
+    fn chain(self, f: f64, df: f64) -> Dual {
+        Dual::new(f, df * self.deriv)
+    }
+}
+
+/**
+Evaluates the derivative of `func` at `x`.
+
+Parameters
+----------
+* `func` : A differentiable function of a dual number.
+* `x` : The point at which to differentiate.
+
+Returns
+-------
+* `f64` : The derivative of `func` at `x`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+let res:f64 = autodiff::derivative(&test_function::generic::trig, 0.0);
+assert_eq!(-1.0, res);
+```
+*/
+pub fn derivative(func: &dyn Fn(Dual) -> Dual, x: f64) -> f64 {
+    func(Dual::variable(x)).deriv
+}
+
+impl PartialEq for Dual {
+    fn eq(&self, other: &Dual) -> bool {
+        self.val == other.val
+    }
+}
+
+impl PartialOrd for Dual {
+    fn partial_cmp(&self, other: &Dual) -> Option<Ordering> {
+        self.val.partial_cmp(&other.val)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        Dual::new(-self.val, -self.deriv)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self.val + rhs.val, self.deriv + rhs.deriv)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual::new(self.val - rhs.val, self.deriv - rhs.deriv)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual::new(
+            self.val * rhs.val,
+            self.deriv * rhs.val + self.val * rhs.deriv,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, rhs: Dual) -> Dual {
+        Dual::new(
+            self.val / rhs.val,
+            (self.deriv * rhs.val - self.val * rhs.deriv) / (rhs.val * rhs.val),
+        )
+    }
+}
+
+impl Rem for Dual {
+    type Output = Dual;
+    fn rem(self, rhs: Dual) -> Dual {
+        // a % b = a - b·trunc(a/b), and trunc is locally constant.
+        let quot: f64 = (self.val / rhs.val).trunc();
+        Dual::new(self.val % rhs.val, self.deriv - rhs.deriv * quot)
+    }
+}
+
+// Mixed arithmetic with `f64`, so that constants needn't be wrapped by hand.
+macro_rules! impl_f64_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait<f64> for Dual {
+            type Output = Dual;
+            fn $method(self, rhs: f64) -> Dual {
+                self.$method(Dual::constant(rhs))
+            }
+        }
+
+        impl $trait<Dual> for f64 {
+            type Output = Dual;
+            fn $method(self, rhs: Dual) -> Dual {
+                Dual::constant(self).$method(rhs)
+            }
+        }
+    };
+}
+
+impl_f64_op!(Add, add);
+impl_f64_op!(Sub, sub);
+impl_f64_op!(Mul, mul);
+impl_f64_op!(Div, div);
+
+impl Zero for Dual {
+    fn zero() -> Dual {
+        Dual::constant(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.val == 0.0
+    }
+}
+
+impl One for Dual {
+    fn one() -> Dual {
+        Dual::constant(1.0)
+    }
+}
+
+impl Num for Dual {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Dual, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Dual::constant)
+    }
+}
+
+impl ToPrimitive for Dual {
+    fn to_i64(&self) -> Option<i64> {
+        self.val.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.val.to_u64()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.val)
+    }
+}
+
+impl NumCast for Dual {
+    fn from<N: ToPrimitive>(n: N) -> Option<Dual> {
+        n.to_f64().map(Dual::constant)
+    }
+}
+
+impl Float for Dual {
+    fn nan() -> Dual {
+        Dual::constant(f64::NAN)
+    }
+    fn infinity() -> Dual {
+        Dual::constant(f64::INFINITY)
+    }
+    fn neg_infinity() -> Dual {
+        Dual::constant(f64::NEG_INFINITY)
+    }
+    fn neg_zero() -> Dual {
+        Dual::constant(-0.0)
+    }
+    fn min_value() -> Dual {
+        Dual::constant(f64::MIN)
+    }
+    fn min_positive_value() -> Dual {
+        Dual::constant(f64::MIN_POSITIVE)
+    }
+    fn epsilon() -> Dual {
+        Dual::constant(f64::EPSILON)
+    }
+    fn max_value() -> Dual {
+        Dual::constant(f64::MAX)
+    }
+    fn is_nan(self) -> bool {
+        self.val.is_nan() || self.deriv.is_nan()
+    }
+    fn is_infinite(self) -> bool {
+        self.val.is_infinite() || self.deriv.is_infinite()
+    }
+    fn is_finite(self) -> bool {
+        self.val.is_finite() && self.deriv.is_finite()
+    }
+    fn is_normal(self) -> bool {
+        self.val.is_normal()
+    }
+    fn classify(self) -> FpCategory {
+        self.val.classify()
+    }
+
+    // Step functions are locally constant, so have zero derivative away from their jumps.
+    fn floor(self) -> Dual {
+        Dual::constant(self.val.floor())
+    }
+    fn ceil(self) -> Dual {
+        Dual::constant(self.val.ceil())
+    }
+    fn round(self) -> Dual {
+        Dual::constant(self.val.round())
+    }
+    fn trunc(self) -> Dual {
+        Dual::constant(self.val.trunc())
+    }
+    fn fract(self) -> Dual {
+        Dual::new(self.val.fract(), self.deriv)
+    }
+    fn signum(self) -> Dual {
+        Dual::constant(self.val.signum())
+    }
+
+    fn abs(self) -> Dual {
+        self.chain(self.val.abs(), self.val.signum())
+    }
+    fn is_sign_positive(self) -> bool {
+        self.val.is_sign_positive()
+    }
+    fn is_sign_negative(self) -> bool {
+        self.val.is_sign_negative()
+    }
+    fn mul_add(self, a: Dual, b: Dual) -> Dual {
+        self * a + b
+    }
+    fn recip(self) -> Dual {
+        self.chain(self.val.recip(), -self.val.powi(-2))
+    }
+    fn powi(self, n: i32) -> Dual {
+        if n == 0 {
+            return Dual::one();
+        }
+        self.chain(self.val.powi(n), n as f64 * self.val.powi(n - 1))
+    }
+    fn powf(self, n: Dual) -> Dual {
+        // d(a^b) = a^b (b' ln(a) + b a'/a); the `ln` term vanishes for constant exponents, even at a <= 0.
+        let val: f64 = self.val.powf(n.val);
+        let base_term: f64 = if self.deriv == 0.0 {
+            0.0
+        } else {
+            n.val * self.val.powf(n.val - 1.0) * self.deriv
+        };
+        let exp_term: f64 = if n.deriv == 0.0 {
+            0.0
+        } else {
+            val * self.val.ln() * n.deriv
+        };
+        Dual::new(val, base_term + exp_term)
+    }
+    fn sqrt(self) -> Dual {
+        let root: f64 = self.val.sqrt();
+        self.chain(root, 0.5 / root)
+    }
+    fn exp(self) -> Dual {
+        let exp: f64 = self.val.exp();
+        self.chain(exp, exp)
+    }
+    fn exp2(self) -> Dual {
+        let exp2: f64 = self.val.exp2();
+        self.chain(exp2, exp2 * core::f64::consts::LN_2)
+    }
+    fn ln(self) -> Dual {
+        self.chain(self.val.ln(), self.val.recip())
+    }
+    fn log(self, base: Dual) -> Dual {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Dual {
+        self.chain(
+            self.val.log2(),
+            (self.val * core::f64::consts::LN_2).recip(),
+        )
+    }
+    fn log10(self) -> Dual {
+        self.chain(
+            self.val.log10(),
+            (self.val * core::f64::consts::LN_10).recip(),
+        )
+    }
+    fn max(self, other: Dual) -> Dual {
+        if other.val > self.val || self.val.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Dual) -> Dual {
+        if other.val < self.val || self.val.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Dual) -> Dual {
+        (self - other).max(Dual::zero())
+    }
+    fn cbrt(self) -> Dual {
+        let root: f64 = self.val.cbrt();
+        self.chain(root, (3.0 * root * root).recip())
+    }
+    fn hypot(self, other: Dual) -> Dual {
+        (self * self + other * other).sqrt()
+    }
+    fn sin(self) -> Dual {
+        self.chain(self.val.sin(), self.val.cos())
+    }
+    fn cos(self) -> Dual {
+        self.chain(self.val.cos(), -self.val.sin())
+    }
+    fn tan(self) -> Dual {
+        let tan: f64 = self.val.tan();
+        self.chain(tan, 1.0 + tan * tan)
+    }
+    fn asin(self) -> Dual {
+        self.chain(self.val.asin(), (1.0 - self.val * self.val).sqrt().recip())
+    }
+    fn acos(self) -> Dual {
+        self.chain(self.val.acos(), -(1.0 - self.val * self.val).sqrt().recip())
+    }
+    fn atan(self) -> Dual {
+        self.chain(self.val.atan(), (1.0 + self.val * self.val).recip())
+    }
+    fn atan2(self, other: Dual) -> Dual {
+        let denom: f64 = self.val * self.val + other.val * other.val;
+        Dual::new(
+            self.val.atan2(other.val),
+            (other.val * self.deriv - self.val * other.deriv) / denom,
+        )
+    }
+    fn sin_cos(self) -> (Dual, Dual) {
+        (self.sin(), self.cos())
+    }
+    fn exp_m1(self) -> Dual {
+        self.chain(self.val.exp_m1(), self.val.exp())
+    }
+    fn ln_1p(self) -> Dual {
+        self.chain(self.val.ln_1p(), (1.0 + self.val).recip())
+    }
+    fn sinh(self) -> Dual {
+        self.chain(self.val.sinh(), self.val.cosh())
+    }
+    fn cosh(self) -> Dual {
+        self.chain(self.val.cosh(), self.val.sinh())
+    }
+    fn tanh(self) -> Dual {
+        let tanh: f64 = self.val.tanh();
+        self.chain(tanh, 1.0 - tanh * tanh)
+    }
+    fn asinh(self) -> Dual {
+        self.chain(self.val.asinh(), (self.val * self.val + 1.0).sqrt().recip())
+    }
+    fn acosh(self) -> Dual {
+        self.chain(self.val.acosh(), (self.val * self.val - 1.0).sqrt().recip())
+    }
+    fn atanh(self) -> Dual {
+        self.chain(self.val.atanh(), (1.0 - self.val * self.val).recip())
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.val.integer_decode()
+    }
+}
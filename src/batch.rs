@@ -0,0 +1,310 @@
+/*!
+Batch experiments: runs every combination of function, method, tolerance and starting point described by a TOML
+config, and collects the results into one comparison table.
+
+Only available with the `batch` feature.
+
+A config looks like
+```toml
+functions = ["trig", "polynom"]
+expressions = ["x - exp(-x)"]
+methods = ["bisection", "newton", "newton-multiplicity"]
+tolerances = [1e-5, 1e-10]
+ks = [2.85, 2.95, 16.0]
+starting_points = [-4.8, 5.0]
+intervals = [[-4.0, 0.0], [0.0, 1.0]]
+max_iter = 100
+max_evals = 200
+```
+where `methods` and `functions` take the same names as the command-line interface, bisection and the hybrid method are
+run over each of `intervals` and the other methods from each of `starting_points`, and the `frac` method is run for each
+of `ks`, or if there are none, for the single value `k`. Every field except `methods` may be omitted; without
+`max_evals`, the number of function evaluations is unlimited.
+
+Functions
+---------
+* `run` : Runs every case of a batch.
+* `to_csv` : Formats the outcomes of a batch as CSV.
+* `to_markdown` : Formats the outcomes of a batch as a Markdown table.
+
+Types
+-----
+* `BatchConfig` : The cases to run, as read from a TOML file.
+* `CaseOutcome` : The description and outcome of a single case.
+*/
+
+use cli::{self, Case, Function, Method};
+use expr::Expr;
+use root_search::Budget;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+fn default_tolerances() -> Vec<f64> {
+    vec![1e-5]
+}
+
+fn default_starting_points() -> Vec<f64> {
+    vec![0.0]
+}
+
+fn default_max_iter() -> usize {
+    100
+}
+
+/// The cases to run: every combination of the listed functions, methods, tolerances and starting points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchConfig {
+    /// The built-in test functions.
+    #[serde(default)]
+    pub functions: Vec<Function>,
+    /// Functions of `x` to parse with `expr::Expr::parse`.
+    #[serde(default)]
+    pub expressions: Vec<String>,
+    pub methods: Vec<Method>,
+    #[serde(default = "default_tolerances")]
+    pub tolerances: Vec<f64>,
+    /// The initial guesses for the iterative methods.
+    #[serde(default = "default_starting_points")]
+    pub starting_points: Vec<f64>,
+    /// The search intervals for the bracketing methods.
+    #[serde(default)]
+    pub intervals: Vec<(f64, f64)>,
+    /// The parameter `k` of the `frac` method.
+    #[serde(default)]
+    pub k: f64,
+    /// Values of `k` to run the `frac` method with, in place of `k`.
+    #[serde(default)]
+    pub ks: Vec<f64>,
+    #[serde(default = "default_max_iter")]
+    pub max_iter: usize,
+    /// The maximum number of function evaluations, for the iterative methods.
+    #[serde(default)]
+    pub max_evals: Option<usize>,
+}
+
+impl BatchConfig {
+    /**
+    Parses a config from TOML.
+
+    Errors
+    ------
+    * `toml::de::Error` : If `input` is not valid TOML, or doesn't describe a config.
+    */
+    pub fn from_toml(input: &str) -> Result<BatchConfig, ::toml::de::Error> {
+        ::toml::from_str(input)
+    }
+
+    /// Reads a config from a TOML file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<BatchConfig, Box<dyn Error>> {
+        Ok(BatchConfig::from_toml(&fs::read_to_string(path)?)?)
+    }
+}
+
+/// The description and outcome of a single case of a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseOutcome {
+    /// The name of the test function, or the expression.
+    pub function: String,
+    pub method: Method,
+    pub tol: f64,
+    /// The starting point, or for the bracketing methods the search interval.
+    pub start: String,
+    /// The parameter `k`, for the `frac` method.
+    pub k: Option<f64>,
+    /// The root, number of iterations taken and number of function evaluations made, or why the search failed.
+    pub outcome: Result<(f64, usize, usize), String>,
+}
+
+/// The name by which the command-line interface and configs refer to a value of a `clap::ValueEnum`.
+fn name<E: ::clap::ValueEnum>(value: &E) -> String {
+    value
+        .to_possible_value()
+        .map_or_else(String::new, |v| v.get_name().to_string())
+}
+
+/**
+Runs every case of a batch, in the order function, method, tolerance, `k`, starting point.
+
+Cases whose expression fails to parse, or whose search fails, are reported as failures rather than stopping the batch.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+let config = batch::BatchConfig::from_toml(r#"
+    functions = ["trig"]
+    methods = ["newton", "bisection"]
+    starting_points = [-4.8]
+    intervals = [[-4.0, 0.0]]
+"#)?;
+println!("{}", batch::to_markdown(&batch::run(&config)));
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+---
+In this example, the `frac` method is run on `trig` for three values of `k`, and converges only for the two near the
+critical value 2.9.
+```rust
+# use root_finding::*;
+let config = batch::BatchConfig::from_toml(r#"
+    functions = ["trig"]
+    methods = ["frac"]
+    starting_points = [-2.0]
+    ks = [2.85, 2.95, 16.0]
+    max_iter = 20
+"#)?;
+let outcomes = batch::run(&config);
+assert_eq!(vec![Some(2.85), Some(2.95), Some(16.0)], outcomes.iter().map(|case| case.k).collect::<Vec<_>>());
+assert_eq!(vec![true, true, false], outcomes.iter().map(|case| case.outcome.is_ok()).collect::<Vec<_>>());
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn run(config: &BatchConfig) -> Vec<CaseOutcome> {
+    let mut targets: Vec<(String, Function, Result<Option<Expr>, String>)> = config
+        .functions
+        .iter()
+        .map(|&function| (name(&function), function, Ok(None)))
+        .collect();
+    targets.extend(config.expressions.iter().map(|input| {
+        (
+            input.clone(),
+            Function::Identity,
+            Expr::parse(input).map(Some).map_err(|err| err.to_string()),
+        )
+    }));
+
+    let ks: Vec<f64> = if config.ks.is_empty() {
+        vec![config.k]
+    } else {
+        config.ks.clone()
+    };
+    let mut outcomes: Vec<CaseOutcome> = Vec::new();
+    for (label, function, expr) in &targets {
+        for &method in &config.methods {
+            // Only `frac` has a parameter; every other method is run once, with `k` unused.
+            let method_ks: &[f64] = if method == Method::Frac {
+                &ks
+            } else {
+                &ks[..1]
+            };
+            for (&tol, &k) in config
+                .tolerances
+                .iter()
+                .flat_map(|tol| method_ks.iter().map(move |k| (tol, k)))
+            {
+                let starts: Vec<(String, f64, (f64, f64))> = if method.is_bracketing() {
+                    config
+                        .intervals
+                        .iter()
+                        .map(|&(a, b)| (format!("[{}, {}]", a, b), 0.0, (a, b)))
+                        .collect()
+                } else {
+                    config
+                        .starting_points
+                        .iter()
+                        .map(|&x0| (x0.to_string(), x0, (0.0, 0.0)))
+                        .collect()
+                };
+                for (start, x0, interval) in starts {
+                    let outcome: Result<(f64, usize, usize), String> = match expr {
+                        Ok(expr) => cli::solve(&Case {
+                            method,
+                            function: *function,
+                            expr: expr.as_ref(),
+                            x0,
+                            interval,
+                            k,
+                            tol,
+                            budget: Budget::new(config.max_iter, config.max_evals),
+                        })
+                        .map(|result| (result.root, result.history.len(), result.evaluations))
+                        .map_err(|err| err.to_string()),
+                        Err(err) => Err(err.clone()),
+                    };
+                    outcomes.push(CaseOutcome {
+                        function: label.clone(),
+                        method,
+                        tol,
+                        start,
+                        k: if method == Method::Frac {
+                            Some(k)
+                        } else {
+                            None
+                        },
+                        outcome,
+                    });
+                }
+            }
+        }
+    }
+    outcomes
+}
+
+/// The cells of each outcome's row, in the order function, method, tolerance, start, k, root, iterations,
+/// evaluations, error.
+fn rows(outcomes: &[CaseOutcome]) -> Vec<[String; 9]> {
+    outcomes
+        .iter()
+        .map(|case| {
+            let (root, iterations, evaluations, error): (String, String, String, String) =
+                match case.outcome {
+                    Ok((root, iterations, evaluations)) => (
+                        root.to_string(),
+                        iterations.to_string(),
+                        evaluations.to_string(),
+                        String::new(),
+                    ),
+                    Err(ref err) => (String::new(), String::new(), String::new(), err.clone()),
+                };
+            [
+                case.function.clone(),
+                name(&case.method),
+                case.tol.to_string(),
+                case.start.clone(),
+                case.k.map_or_else(String::new, |k| k.to_string()),
+                root,
+                iterations,
+                evaluations,
+                error,
+            ]
+        })
+        .collect()
+}
+
+const HEADER: [&str; 9] = [
+    "function",
+    "method",
+    "tol",
+    "start",
+    "k",
+    "root",
+    "iterations",
+    "evaluations",
+    "error",
+];
+
+/// Formats the outcomes of a batch as CSV, quoting every field.
+pub fn to_csv(outcomes: &[CaseOutcome]) -> String {
+    let mut out: String = format!("{}\n", HEADER.join(","));
+    for row in rows(outcomes) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|cell| format!("\"{}\"", cell.replace('"', "\"\"")))
+            .collect();
+        out.push_str(&format!("{}\n", cells.join(",")));
+    }
+    out
+}
+
+/// Formats the outcomes of a batch as a Markdown table.
+pub fn to_markdown(outcomes: &[CaseOutcome]) -> String {
+    let mut out: String = format!("| {} |\n", HEADER.join(" | "));
+    out.push_str(&format!("|{}\n", " --- |".repeat(HEADER.len())));
+    for row in rows(outcomes) {
+        let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
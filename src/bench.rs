@@ -0,0 +1,184 @@
+/*!
+Benchmarks: runs every method of the command-line interface on every test problem, at each of `TOLERANCES`, and
+measures the iterations, function evaluations and time each takes.
+
+The bracketing methods search the problem's `domain`, and the others start from its midpoint, so a method may fail,
+or converge to a different root than its neighbours; failures are reported rather than skipped, since robustness is
+part of the comparison. The same cases are timed statistically by the `criterion` suite in `benches/solvers.rs`, run
+with `cargo bench`, and quickly by `root-finder bench`.
+
+Functions
+---------
+* `case` : The search for a root of a test problem by a method, to a tolerance.
+* `run` : Measures every method on every test problem.
+* `to_csv` : Formats measurements as CSV.
+* `to_markdown` : Formats measurements as a Markdown table.
+
+Types
+-----
+* `Measurement` : The cost of one method on one test problem, to one tolerance.
+*/
+
+use clap::ValueEnum;
+use cli::{self, Case, Function, Method};
+use root_search::{Budget, RootResult};
+use std::time::{Duration, Instant};
+
+/// The tolerances every method is run to.
+pub const TOLERANCES: [f64; 3] = [1e-4, 1e-8, 1e-12];
+
+/// The maximum number of iterations of each search.
+const MAX_ITER: usize = 100;
+
+/**
+The search for a root of the test problem `function` by `method`, to within `tol`.
+
+Bracketing methods search the problem's domain, and the others start from its midpoint.
+*/
+pub fn case(function: Function, method: Method, tol: f64) -> Case<'static> {
+    let domain: (f64, f64) = function.problem().domain;
+    Case {
+        method,
+        function,
+        expr: None,
+        x0: (domain.0 + domain.1) / 2.0,
+        interval: domain,
+        k: 0.0,
+        tol,
+        budget: Budget::new(MAX_ITER, None),
+    }
+}
+
+/// The cost of one method on one test problem, to one tolerance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub function: Function,
+    pub method: Method,
+    pub tol: f64,
+    /// The number of iterations, the number of function evaluations, and the distance from the root found to the
+    /// nearest known root; or why the search failed.
+    pub outcome: Result<(usize, usize, f64), String>,
+    /// The mean time taken by a search.
+    pub time: Duration,
+}
+
+/**
+Runs every method on every test problem to each of `TOLERANCES`, timing the mean of `repetitions` runs of each.
+
+Examples
+--------
+In this example, each step of Newton-Raphson on `trig` evaluates the function and its derivative once each.
+```rust
+# use root_finding::*;
+let measurements = bench::run(1);
+let newton = measurements
+    .iter()
+    .find(|m| m.function == cli::Function::Trig && m.method == cli::Method::Newton && m.tol == 1e-12)
+    .unwrap();
+let (iterations, evaluations, error) = newton.outcome.clone()?;
+assert!(iterations < 10 && evaluations == 2 * iterations && error < 1e-12);
+# Ok::<(), String>(())
+```
+*/
+pub fn run(repetitions: usize) -> Vec<Measurement> {
+    let repetitions: usize = repetitions.max(1);
+    let mut measurements: Vec<Measurement> = Vec::new();
+    for &function in Function::value_variants() {
+        for &method in Method::value_variants() {
+            for &tol in &TOLERANCES {
+                let case: Case = case(function, method, tol);
+                let start: Instant = Instant::now();
+                let mut res: Result<RootResult, String> = Err(String::new());
+                for _ in 0..repetitions {
+                    res = cli::solve(&case).map_err(|err| err.to_string());
+                }
+                let time: Duration = start.elapsed() / repetitions as u32;
+                measurements.push(Measurement {
+                    function,
+                    method,
+                    tol,
+                    outcome: res.map(|res| {
+                        (
+                            res.history.len(),
+                            res.evaluations,
+                            function.problem().distance_to_root(res.root),
+                        )
+                    }),
+                    time,
+                });
+            }
+        }
+    }
+    measurements
+}
+
+/// The name by which the command-line interface refers to a value of a `clap::ValueEnum`.
+fn name<E: ValueEnum>(value: &E) -> String {
+    value
+        .to_possible_value()
+        .map_or_else(String::new, |v| v.get_name().to_string())
+}
+
+/// The cells of each measurement's row, in the order of `HEADER`.
+fn rows(measurements: &[Measurement]) -> Vec<[String; 8]> {
+    measurements
+        .iter()
+        .map(|m| {
+            let (iterations, evaluations, error, failure): (String, String, String, String) =
+                match m.outcome {
+                    Ok((iterations, evaluations, error)) => (
+                        iterations.to_string(),
+                        evaluations.to_string(),
+                        format!("{:.1e}", error),
+                        String::new(),
+                    ),
+                    Err(ref err) => (String::new(), String::new(), String::new(), err.clone()),
+                };
+            [
+                name(&m.function),
+                name(&m.method),
+                format!("{:e}", m.tol),
+                iterations,
+                evaluations,
+                error,
+                format!("{:.2}", m.time.as_secs_f64() * 1e6),
+                failure,
+            ]
+        })
+        .collect()
+}
+
+const HEADER: [&str; 8] = [
+    "function",
+    "method",
+    "tol",
+    "iterations",
+    "evaluations",
+    "error",
+    "time (µs)",
+    "failure",
+];
+
+/// Formats measurements as CSV, quoting every field.
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut out: String = format!("{}\n", HEADER.join(","));
+    for row in rows(measurements) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|cell| format!("\"{}\"", cell.replace('"', "\"\"")))
+            .collect();
+        out.push_str(&format!("{}\n", cells.join(",")));
+    }
+    out
+}
+
+/// Formats measurements as a Markdown table.
+pub fn to_markdown(measurements: &[Measurement]) -> String {
+    let mut out: String = format!("| {} |\n", HEADER.join(" | "));
+    out.push_str(&format!("|{}\n", " --- |".repeat(HEADER.len())));
+    for row in rows(measurements) {
+        let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        out.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    out
+}
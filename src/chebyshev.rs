@@ -0,0 +1,289 @@
+/*!
+Chebyshev interpolants, and a root-finder using them as proxies for smooth functions.
+
+A smooth function on an interval is approximated to near machine precision by a polynomial interpolating it at
+Chebyshev points, of modest degree, whose roots are cheap to find. This is the idea behind
+[Chebfun](https://www.chebfun.org/).
+
+Types
+-----
+* `Chebyshev` : A polynomial on an interval, stored by its coefficients in the Chebyshev basis.
+
+Functions
+---------
+* `all_roots` : Finds every root of a smooth function in an interval, from a piecewise Chebyshev proxy.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::f64::consts::PI;
+use root_search::{self, Budget, CountedFunction, RootError, RootResult, TerminationReason};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use BatchedFunction;
+// `core` has no `f64::cos`, so without `std` it comes from `Float`.
+#[cfg(not(feature = "std"))]
+use Float;
+
+/// The degrees tried in turn when interpolating a piece of the function.
+const DEGREES: [usize; 5] = [8, 16, 32, 64, 128];
+
+/// How small the trailing coefficients must be, relative to the largest, for an interpolant to be resolved.
+const RESOLUTION: f64 = 1e-13;
+
+/// The maximum number of times an interval is halved in search of resolved interpolants.
+const MAX_DEPTH: usize = 12;
+
+/// The number of sign-change subdivisions per degree when scanning an interpolant for roots.
+const SCAN_DENSITY: usize = 4;
+
+/// The maximum number of Newton-Raphson iterations spent polishing each root.
+const MAX_ITER: usize = 50;
+
+/**
+A polynomial `c_0 T_0(t) + c_1 T_1(t) + ... + c_n T_n(t)` on an interval `[a, b]`, where `T_k` are the Chebyshev
+polynomials and `t = (2x - a - b)/(b - a)` maps the interval onto `[-1, 1]`.
+
+Examples
+--------
+In this example, we interpolate `exp` on [0, 1] at degree 16, which agrees with it to near machine precision.
+```rust
+# use root_finding::*;
+let cheb = chebyshev::Chebyshev::interpolate(&(|x: f64| x.exp()), (0.0, 1.0), 16);
+assert!((cheb.eval(0.3) - 0.3_f64.exp()).abs() < 1e-14);
+assert!((cheb.derivative().eval(0.3) - 0.3_f64.exp()).abs() < 1e-12);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chebyshev {
+    domain: (f64, f64),
+    coeffs: Vec<f64>,
+}
+
+impl Chebyshev {
+    /**
+    Interpolates a function at the `degree + 1` Chebyshev points `x_j = cos(jπ/n)`, mapped onto `domain`.
+
+    The function is evaluated at all the points in one call to `BatchedFunction::eval_many`, and the coefficients are
+    computed by the discrete cosine transform of its values.
+    */
+    pub fn interpolate<F: BatchedFunction + ?Sized>(
+        func: &F,
+        domain: (f64, f64),
+        degree: usize,
+    ) -> Chebyshev {
+        let n: usize = degree.max(1);
+        let (a, b): (f64, f64) = domain;
+        let points: Vec<f64> = (0..=n)
+            .map(|j| {
+                let t: f64 = (j as f64 * PI / n as f64).cos();
+                (a + b) / 2.0 + t * (b - a) / 2.0
+            })
+            .collect();
+        let mut values: Vec<f64> = vec![0.0; n + 1];
+        func.eval_many(&points, &mut values);
+        // Endpoint terms of the sums, and the first and last coefficients, are halved.
+        let halve = |j: usize| -> f64 {
+            if j == 0 || j == n {
+                0.5
+            } else {
+                1.0
+            }
+        };
+        let coeffs: Vec<f64> = (0..=n)
+            .map(|k| {
+                let sum: f64 = (0..=n)
+                    .map(|j| halve(j) * values[j] * ((k * j) as f64 * PI / n as f64).cos())
+                    .sum();
+                halve(k) * 2.0 * sum / n as f64
+            })
+            .collect();
+        Chebyshev { domain, coeffs }
+    }
+
+    /// The coefficients `[c_0, c_1, ..., c_n]`.
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    pub fn domain(&self) -> (f64, f64) {
+        self.domain
+    }
+
+    /// Evaluates the polynomial at `x` by Clenshaw's recurrence.
+    pub fn eval(&self, x: f64) -> f64 {
+        let (a, b): (f64, f64) = self.domain;
+        let t: f64 = (2.0 * x - a - b) / (b - a);
+        let (mut b1, mut b2): (f64, f64) = (0.0, 0.0);
+        for &c in self.coeffs.iter().skip(1).rev() {
+            let b0: f64 = c + 2.0 * t * b1 - b2;
+            b2 = b1;
+            b1 = b0;
+        }
+        self.coeffs[0] + t * b1 - b2
+    }
+
+    /// The derivative, on the same interval.
+    pub fn derivative(&self) -> Chebyshev {
+        let n: usize = self.degree();
+        if n == 0 {
+            return Chebyshev {
+                domain: self.domain,
+                coeffs: vec![0.0],
+            };
+        }
+        let mut deriv: Vec<f64> = vec![0.0; n + 1];
+        for k in (1..=n).rev() {
+            deriv[k - 1] =
+                deriv.get(k + 1).copied().unwrap_or(0.0) + 2.0 * k as f64 * self.coeffs[k];
+        }
+        deriv[0] /= 2.0;
+        deriv.truncate(n);
+        let scale: f64 = 2.0 / (self.domain.1 - self.domain.0);
+        Chebyshev {
+            domain: self.domain,
+            coeffs: deriv.iter().map(|c| c * scale).collect(),
+        }
+    }
+
+    /// Whether the trailing coefficients are negligible, so the polynomial resolves the function it interpolates.
+    fn is_resolved(&self) -> bool {
+        let scale: f64 = self.coeffs.iter().fold(0.0, |max, c| c.abs().max(max));
+        let n: usize = self.degree();
+        let tail: f64 = self.coeffs[n.saturating_sub(1)..]
+            .iter()
+            .fold(0.0, |max, c| c.abs().max(max));
+        tail <= RESOLUTION * scale
+    }
+}
+
+/// Interpolates `func` on `domain` at increasing degree, halving the interval if even the highest doesn't resolve it.
+fn pieces<F: BatchedFunction + ?Sized>(
+    func: &F,
+    domain: (f64, f64),
+    depth: usize,
+) -> Vec<Chebyshev> {
+    let mut cheb: Option<Chebyshev> = None;
+    for &degree in DEGREES.iter() {
+        let fitted: Chebyshev = Chebyshev::interpolate(func, domain, degree);
+        let resolved: bool = fitted.is_resolved();
+        cheb = Some(fitted);
+        if resolved {
+            break;
+        }
+    }
+    let cheb: Chebyshev = cheb.unwrap();
+    if cheb.is_resolved() || depth >= MAX_DEPTH {
+        return vec![cheb];
+    }
+    let mid: f64 = (domain.0 + domain.1) / 2.0;
+    let mut res: Vec<Chebyshev> = pieces(func, (domain.0, mid), depth + 1);
+    res.extend(pieces(func, (mid, domain.1), depth + 1));
+    res
+}
+
+/**
+Finds every root of a smooth function in an interval, using a piecewise Chebyshev interpolant as a proxy.
+
+The function is interpolated at Chebyshev points, doubling the degree up to 128 until the trailing coefficients are
+negligible, and halving the interval wherever that isn't enough. The roots of each interpolant are then found by
+scanning it for sign changes and bisecting, which costs no evaluations of `func`, and each is polished by Newton-Raphson
+on `func`, using the derivative of the interpolant; if that fails to converge, the root of the interpolant is kept. As
+with `root_search::find_all_roots`, roots where the function touches zero without crossing it are missed.
+
+Parameters
+----------
+* `func` : A smooth function, finite over the whole of `domain`.
+* `domain` : The start and end points of the search interval.
+* `trunc_err` : A float representing the acceptable truncation error for each root.
+
+Returns
+-------
+* `Vec<RootResult>` : The roots found, in increasing order, each with its Newton-Raphson iterates and the evaluations of
+  `func` made in polishing it. The evaluations made in interpolating are not included.
+
+Errors
+------
+* `RootError` : If `func` is NaN or infinite at a Newton-Raphson iterate.
+
+Examples
+--------
+In this example, `chebyshev::all_roots` finds the seven roots of sin(x) in [-10, 10].
+```rust
+# use root_finding::*;
+let res = chebyshev::all_roots(&test_function::sine, (-10.0, 10.0), 1e-12)?;
+assert_eq!(7, res.len());
+assert!((res[4].root - std::f64::consts::PI).abs() < 1e-12);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn all_roots<F: BatchedFunction + ?Sized>(
+    func: &F,
+    domain: (f64, f64),
+    trunc_err: f64,
+) -> Result<Vec<RootResult>, RootError> {
+    trace_span!("chebyshev::all_roots");
+    let mut roots: Vec<RootResult> = Vec::new();
+    for cheb in pieces(func, domain, 0) {
+        trace_debug!(
+            lo = cheb.domain.0,
+            hi = cheb.domain.1,
+            degree = cheb.degree(),
+            "interpolated piece"
+        );
+        let deriv: Chebyshev = cheb.derivative();
+        let proxy = |x: f64| -> f64 { cheb.eval(x) };
+        let proxy_roots: Vec<RootResult> = root_search::find_all_roots(
+            &proxy,
+            cheb.domain(),
+            SCAN_DENSITY * cheb.degree(),
+            trunc_err,
+        );
+        for proxy_root in proxy_roots {
+            let counted: CountedFunction<F> = CountedFunction::new(func);
+            // `fixed_point` only sees the Newton-Raphson map, so record the residuals `F(x)` here.
+            let residuals: RefCell<Vec<f64>> = RefCell::new(Vec::new());
+            let newton = |x: f64| -> f64 {
+                let residual: f64 = counted.call(x);
+                residuals.borrow_mut().push(residual);
+                let slope: f64 = deriv.eval(x);
+                if slope == 0.0 {
+                    x
+                } else {
+                    x - residual / slope
+                }
+            };
+            match root_search::fixed_point(
+                &newton,
+                proxy_root.root,
+                trunc_err,
+                Budget::new(MAX_ITER, None),
+            ) {
+                Ok(res) => roots.push(RootResult {
+                    residuals: residuals.take(),
+                    evaluations: counted.evaluations(),
+                    ..res
+                }),
+                Err(err) if err.reason == TerminationReason::NonFinite => return Err(err),
+                // Rounding error in `func` can keep Newton-Raphson from settling to `trunc_err`, as for Wilkinson's
+                // polynomial, in which case the root of the interpolant is as good an estimate as any.
+                Err(err) => roots.push(RootResult {
+                    root: proxy_root.root,
+                    history: err.history,
+                    residuals: residuals.take(),
+                    evaluations: counted.evaluations(),
+                }),
+            }
+        }
+    }
+    roots.sort_by(|a, b| a.root.partial_cmp(&b.root).unwrap());
+    // A root on the boundary between two pieces may be found in both.
+    roots.dedup_by(|a, b| (a.root - b.root).abs() < trunc_err);
+    Ok(roots)
+}
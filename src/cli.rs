@@ -0,0 +1,472 @@
+/*!
+The command-line interface, for running a single root search without editing and recompiling `main`.
+
+For example, `root-finder --method newton --function trig --x0 -4.8 --tol 1e-5 --max-iter 100 --report table` runs
+Newton-Raphson on `trig` from -4.8 and prints a table of the iterates. Other functions can be given as expressions,
+e.g. `--expr "x - exp(-x)"`, in which case derivatives are computed by automatic differentiation.
+
+Functions
+---------
+* `run` : Runs the root search described by the command-line arguments, printing the report.
+* `solve` : Runs a single root search.
+
+Types
+-----
+* `Args` : The command-line arguments.
+* `Command` : A subcommand, such as `bench`.
+* `Case` : A single root search.
+* `Method` : The root-finding algorithm to run.
+* `Function` : The test function to find a root of.
+* `Report` : How to print the result.
+*/
+
+use analysis;
+use autodiff::{self, Dual};
+#[cfg(feature = "batch")]
+use batch::{self, BatchConfig};
+use bench;
+use clap::{Parser, Subcommand, ValueEnum};
+use expr::Expr;
+use functional::{self, DifferenceScheme};
+use problem;
+use report::{self, TableFormat, TableOptions};
+use root_search::{self, Bracket, Budget, CountedFunction, GlobalPhase, RootResult};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+#[cfg(feature = "batch")]
+use std::path::PathBuf;
+use std::time::Duration;
+use test_func_derivative;
+use test_function::{self, TestProblem};
+use BoxedFn;
+use ContinuousFunction;
+
+/// The root-finding algorithm to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Method {
+    /// Binary search over `[a, b]`.
+    Bisection,
+    /// Chandrupatla's algorithm over `[a, b]`, interpolating where safe and bisecting otherwise.
+    Chandrupatla,
+    /// Fixed point iteration of `x - F(x)/(2+k)`.
+    Frac,
+    /// Newton-Raphson, with the analytic derivative.
+    Newton,
+    /// Newton-Raphson, with a central-difference derivative.
+    NewtonNumerical,
+    /// Newton-Raphson, with the derivative computed by automatic differentiation.
+    NewtonAutodiff,
+    /// Newton-Raphson modified for repeated roots, detecting the multiplicity.
+    NewtonMultiplicity,
+    /// Binary search over `[a, b]` until the bracket is narrower than `sqrt(tol)`, then Newton-Raphson.
+    Hybrid,
+}
+
+impl Method {
+    /// Whether the method searches an interval `[a, b]`, rather than starting from `x0`.
+    pub fn is_bracketing(self) -> bool {
+        matches!(
+            self,
+            Method::Bisection | Method::Chandrupatla | Method::Hybrid
+        )
+    }
+}
+
+/// The test function to find a root of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Function {
+    /// `x`
+    Identity,
+    /// `2x - 3sin(x) + 5`
+    Trig,
+    /// `x^3 - 8.5x^2 + 20x - 8`
+    Polynom,
+    /// `e^x - 2`
+    ExpMinusTwo,
+    /// `xe^-x`
+    XExp,
+    /// `cos(x) - x`
+    CosMinusX,
+    /// `sin(x)`
+    Sine,
+    /// `(x-1)^3`
+    Triple,
+    /// `(x-1)(x-2)...(x-10)`
+    Wilkinson,
+    /// `x - e^-x`
+    XMinusExp,
+    /// `x^3 - 2x - 5`
+    NewtonCubic,
+    /// `x^3 - 3x + 2`, with a double root at 1
+    DoubleRoot,
+}
+
+impl Function {
+    fn func(self) -> fn(f64) -> f64 {
+        match self {
+            Function::Identity => test_function::identity,
+            Function::Trig => test_function::trig,
+            Function::Polynom => test_function::polynom,
+            Function::ExpMinusTwo => test_function::exp_minus_two,
+            Function::XExp => test_function::x_exp,
+            Function::CosMinusX => test_function::cos_minus_x,
+            Function::Sine => test_function::sine,
+            Function::Triple => test_function::triple,
+            Function::Wilkinson => test_function::wilkinson,
+            Function::XMinusExp => test_function::x_minus_exp,
+            Function::NewtonCubic => test_function::newton_cubic,
+            Function::DoubleRoot => test_function::double_root,
+        }
+    }
+
+    fn deriv(self) -> fn(f64) -> f64 {
+        match self {
+            Function::Identity => test_func_derivative::identity,
+            Function::Trig => test_func_derivative::trig,
+            Function::Polynom => test_func_derivative::polynom,
+            Function::ExpMinusTwo => test_func_derivative::exp_minus_two,
+            Function::XExp => test_func_derivative::x_exp,
+            Function::CosMinusX => test_func_derivative::cos_minus_x,
+            Function::Sine => test_func_derivative::sine,
+            Function::Triple => test_func_derivative::triple,
+            Function::Wilkinson => test_func_derivative::wilkinson,
+            Function::XMinusExp => test_func_derivative::x_minus_exp,
+            Function::NewtonCubic => test_func_derivative::newton_cubic,
+            Function::DoubleRoot => test_func_derivative::double_root,
+        }
+    }
+
+    /// The test problem for the function, with its known roots and a search interval.
+    pub fn problem(self) -> &'static TestProblem {
+        let name: &str = match self {
+            Function::Identity => "identity",
+            Function::Trig => "trig",
+            Function::Polynom => "polynom",
+            Function::ExpMinusTwo => "exp_minus_two",
+            Function::XExp => "x_exp",
+            Function::CosMinusX => "cos_minus_x",
+            Function::Sine => "sine",
+            Function::Triple => "triple",
+            Function::Wilkinson => "wilkinson",
+            Function::XMinusExp => "x_minus_exp",
+            Function::NewtonCubic => "newton_cubic",
+            Function::DoubleRoot => "double_root",
+        };
+        test_function::problem(name).expect("every function is a test problem")
+    }
+
+    fn dual(self) -> fn(Dual) -> Dual {
+        match self {
+            Function::Identity => test_function::generic::identity,
+            Function::Trig => test_function::generic::trig,
+            Function::Polynom => test_function::generic::polynom,
+            Function::ExpMinusTwo => test_function::generic::exp_minus_two,
+            Function::XExp => test_function::generic::x_exp,
+            Function::CosMinusX => test_function::generic::cos_minus_x,
+            Function::Sine => test_function::generic::sine,
+            Function::Triple => test_function::generic::triple,
+            Function::Wilkinson => test_function::generic::wilkinson,
+            Function::XMinusExp => test_function::generic::x_minus_exp,
+            Function::NewtonCubic => test_function::generic::newton_cubic,
+            Function::DoubleRoot => test_function::generic::double_root,
+        }
+    }
+}
+
+/// How to print the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Report {
+    /// Just the root and the number of iterations.
+    Root,
+    /// A Markdown table of the iterates.
+    Table,
+    /// A LaTeX table of the iterates.
+    Latex,
+    /// The iterates as CSV.
+    Csv,
+}
+
+/// Runs a root-finding algorithm on one of the test functions.
+#[derive(Debug, Parser)]
+#[command(name = "root-finder", version, about)]
+pub struct Args {
+    /// The root-finding algorithm.
+    #[arg(long, value_enum, default_value_t = Method::Newton)]
+    pub method: Method,
+    /// The function to find a root of.
+    #[arg(long, value_enum, default_value_t = Function::Trig)]
+    pub function: Function,
+    /// A function of `x` to find a root of instead, e.g. "x - exp(-x)".
+    #[arg(long, allow_hyphen_values = true)]
+    pub expr: Option<Expr>,
+    /// The initial guess, for the iterative methods.
+    #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+    pub x0: f64,
+    /// The left end of the search interval, for the bracketing methods.
+    #[arg(long, default_value_t = -4.0, allow_hyphen_values = true)]
+    pub a: f64,
+    /// The right end of the search interval, for the bracketing methods.
+    #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+    pub b: f64,
+    /// The parameter `k` of the `frac` method.
+    #[arg(long, default_value_t = 0.0, allow_hyphen_values = true)]
+    pub k: f64,
+    /// The acceptable truncation error.
+    #[arg(long, default_value_t = 1e-5)]
+    pub tol: f64,
+    /// The maximum number of iterations.
+    #[arg(long, default_value_t = 100)]
+    pub max_iter: usize,
+    /// The maximum number of function evaluations.
+    #[arg(long)]
+    pub max_evals: Option<usize>,
+    /// The maximum time to search for, in seconds.
+    #[arg(long, value_parser = parse_duration)]
+    pub max_duration: Option<Duration>,
+    /// How to print the result.
+    #[arg(long, value_enum, default_value_t = Report::Root, global = true)]
+    pub report: Report,
+    /// The number of decimal places to print in tables.
+    #[arg(long, default_value_t = 6)]
+    pub precision: usize,
+    /// A TOML config of cases to run and compare, instead of a single search.
+    #[cfg(feature = "batch")]
+    #[arg(long)]
+    pub batch: Option<PathBuf>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Something to do other than a single root search.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Times every method on every test problem, to tolerances 1e-4, 1e-8 and 1e-12.
+    Bench {
+        /// The number of times to run each search, averaging the time taken.
+        #[arg(long, default_value_t = 10)]
+        repetitions: usize,
+    },
+}
+
+/// Parses a number of seconds, e.g. `0.5`, as a `Duration`.
+fn parse_duration(secs: &str) -> Result<Duration, String> {
+    let secs: f64 = secs.parse().map_err(|err| format!("{}", err))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
+}
+
+impl Args {
+    /// The root search described by the arguments.
+    pub fn case(&self) -> Case<'_> {
+        Case {
+            method: self.method,
+            function: self.function,
+            expr: self.expr.as_ref(),
+            x0: self.x0,
+            interval: (self.a, self.b),
+            k: self.k,
+            tol: self.tol,
+            budget: Budget {
+                max_iter: self.max_iter,
+                max_evals: self.max_evals,
+                max_duration: self.max_duration,
+            },
+        }
+    }
+}
+
+/// A single root search: which method to run on which function, and from where.
+#[derive(Debug, Clone, Copy)]
+pub struct Case<'a> {
+    pub method: Method,
+    pub function: Function,
+    /// If set, the function to use instead of `function`.
+    pub expr: Option<&'a Expr>,
+    /// The initial guess, for the iterative methods.
+    pub x0: f64,
+    /// The search interval, for the bracketing methods.
+    pub interval: (f64, f64),
+    /// The parameter `k` of the `frac` method.
+    pub k: f64,
+    pub tol: f64,
+    /// The iterations, function evaluations and time the search may spend.
+    pub budget: Budget,
+}
+
+type DualFunction<'a> = dyn Fn(Dual) -> Dual + 'a;
+
+/// The function to search, its derivative, and the function over dual numbers.
+fn functions<'a>(case: &Case<'a>) -> (BoxedFn<'a>, BoxedFn<'a>, Box<DualFunction<'a>>) {
+    match case.expr {
+        Some(expr) => (
+            expr.function(),
+            Box::new(move |x: f64| -> f64 { autodiff::derivative(&|x: Dual| expr.eval(x), x) }),
+            expr.function(),
+        ),
+        None => (
+            Box::new(case.function.func()),
+            Box::new(case.function.deriv()),
+            Box::new(case.function.dual()),
+        ),
+    }
+}
+
+/**
+Runs a root search, returning the root and iterates.
+
+Errors
+------
+* If the method fails to find a root, exhausts its budget, or evaluates the function where it is NaN or
+  infinite.
+*/
+pub fn solve(case: &Case) -> Result<RootResult, Box<dyn Error>> {
+    let (func, deriv, dual) = functions(case);
+    // The Newton maps evaluate the function, and its derivative, themselves, so `fixed_point` would count one
+    // evaluation per step; these count the evaluations they actually make.
+    let counted_func: CountedFunction = CountedFunction::new(&*func);
+    let counted_deriv: CountedFunction = CountedFunction::new(&*deriv);
+    let counted_dual: CountedFunction<DualFunction> = CountedFunction::new(&*dual);
+    let iterate = |g: &ContinuousFunction| -> Result<RootResult, Box<dyn Error>> {
+        let mut res: RootResult = root_search::fixed_point(g, case.x0, case.tol, case.budget)?;
+        res.evaluations =
+            counted_func.evaluations() + counted_deriv.evaluations() + counted_dual.evaluations();
+        Ok(res)
+    };
+    let func_newton = |x: f64| counted_func.call(x);
+    let deriv_newton = |x: f64| counted_deriv.call(x);
+    let dual_newton = |x: Dual| counted_dual.call(x);
+
+    match case.method {
+        Method::Bisection => {
+            let bracket: Bracket = Bracket::new(&func, case.interval.0, case.interval.1)?;
+            Ok(root_search::binary(&func, bracket, case.tol, case.budget)?.result)
+        }
+        Method::Chandrupatla => {
+            let bracket: Bracket = Bracket::new(&func, case.interval.0, case.interval.1)?;
+            Ok(root_search::chandrupatla(
+                &func,
+                bracket,
+                case.tol,
+                case.budget,
+            )?)
+        }
+        Method::Frac => Ok(root_search::fixed_point(
+            &problem::frac(&func, case.k),
+            case.x0,
+            case.tol,
+            case.budget,
+        )?),
+        Method::Newton => iterate(&functional::x_minus(functional::newton_raphson(
+            func_newton,
+            deriv_newton,
+        ))),
+        Method::NewtonNumerical => iterate(&functional::x_minus(functional::newton_numerical(
+            func_newton,
+            DifferenceScheme::Central,
+            None,
+        ))),
+        Method::NewtonAutodiff => iterate(&functional::x_minus(functional::newton_autodiff(
+            dual_newton,
+        ))),
+        Method::NewtonMultiplicity => Ok(root_search::newton_multiplicity(
+            &func,
+            &deriv,
+            None,
+            case.x0,
+            case.tol,
+            case.budget,
+        )?),
+        Method::Hybrid => {
+            let bracket: Bracket = Bracket::new(&func, case.interval.0, case.interval.1)?;
+            // Newton-Raphson doubles the number of correct digits at each step, so from within `sqrt(tol)` of the
+            // root one or two steps suffice.
+            Ok(root_search::hybrid_newton(
+                &func,
+                &deriv,
+                GlobalPhase::Bisection(bracket),
+                case.tol.sqrt(),
+                case.tol,
+                case.budget,
+            )?
+            .result)
+        }
+    }
+}
+
+/**
+Runs the root search described by the command-line arguments, printing the report to standard output.
+
+The root is printed with the forward error bound of `analysis::sensitivity`, rather than the truncation error, which
+is misleading for an ill-conditioned root. With `--batch`, every case of the config is run instead, and a comparison
+table (or with `--report csv`, CSV) printed. The `bench` subcommand instead times every method on every test problem,
+as `bench::run`, printed the same way.
+
+Errors
+------
+* If the method fails to find a root, or the batch config can't be read.
+*/
+pub fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    if let Some(Command::Bench { repetitions }) = args.command {
+        let measurements: Vec<bench::Measurement> = bench::run(repetitions);
+        match args.report {
+            Report::Csv => print!("{}", bench::to_csv(&measurements)),
+            _ => print!("{}", bench::to_markdown(&measurements)),
+        }
+        return Ok(());
+    }
+    #[cfg(feature = "batch")]
+    {
+        if let Some(ref path) = args.batch {
+            let outcomes: Vec<batch::CaseOutcome> = batch::run(&BatchConfig::load(path)?);
+            match args.report {
+                Report::Csv => print!("{}", batch::to_csv(&outcomes)),
+                _ => print!("{}", batch::to_markdown(&outcomes)),
+            }
+            return Ok(());
+        }
+    }
+
+    let case: Case = args.case();
+    let result: RootResult = solve(&case)?;
+    let table = |format: TableFormat| -> String {
+        let options = TableOptions {
+            format,
+            precision: args.precision,
+            ..Default::default()
+        };
+        report::result_table(&result, &options)
+    };
+
+    match args.report {
+        Report::Root => {
+            let (func, deriv, _) = functions(&case);
+            println!(
+                "{:.*} ± {:.1e} ({} iterations, {} evaluations)",
+                args.precision,
+                result.root,
+                analysis::sensitivity(&func, &deriv, result.root).error_bound,
+                result.history.len(),
+                result.evaluations
+            )
+        }
+        Report::Table => print!("{}", table(TableFormat::Markdown)),
+        Report::Latex => print!("{}", table(TableFormat::Latex)),
+        Report::Csv => print!("{}", result.to_csv()),
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+/*!
+Tracking a root of a parametrised family of functions `F(x; λ)` as the parameter varies.
+
+Functions
+---------
+* `track` : Follows a root of `F(x; λ)` over a sequence of parameter values, by Newton-Raphson with bisection recovery.
+
+Types
+-----
+* `ContinuationPoint` : The root found at one parameter value, and how it was found.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use functional;
+use root_search::{
+    self, Budget, CountedFunction, DomainOrGuess, InvertError, RootError, RootResult,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use BoxedFn;
+use Float;
+
+/// The root of `F(x; λ)` found at one parameter value.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContinuationPoint<T = f64> {
+    /// The parameter value `λ`.
+    pub param: T,
+    /// The root found, or the error from Newton-Raphson if recovery by bisection also failed. Either way, its
+    /// `evaluations` counts every evaluation of `F` and `∂F/∂x` at this parameter value, by both methods.
+    pub result: Result<RootResult<T>, RootError<T>>,
+    /// Whether Newton-Raphson failed, and the root was instead found by bisection.
+    pub bisected: bool,
+    /// Why recovery by bisection failed, if Newton-Raphson failed and so did recovery.
+    #[cfg_attr(feature = "serde", serde(default = "Option::default"))]
+    pub recovery_error: Option<InvertError<T>>,
+}
+
+/**
+Follows a root of a family of functions `F(x; λ)` as `λ` steps through a sequence of values.
+
+At each `λ`, Newton-Raphson is started from the root found at the previous `λ`, which for small enough steps is close to
+the new root, so converges in a few iterations. If it fails, e.g. because the root has moved past a turning point of
+`F`, a bracket is grown around the previous root and searched by bisection, as by `root_search::invert`. If that
+fails too, the point records the errors from both Newton-Raphson and the recovery, and the next `λ` starts from the
+last root found.
+
+Parameters
+----------
+* `func` : The family of functions, as `F(x, λ)`.
+* `deriv` : The derivative `∂F/∂x`, as a function of `(x, λ)`.
+* `root` : A root of `F(x; λ)` at, or near, the first parameter value.
+* `params` : The parameter values, in the order to visit them.
+* `trunc_err` : A float representing the acceptable truncation error for each root.
+* `max_iter` : The maximum number of Newton-Raphson iterations at each parameter value.
+
+Returns
+-------
+* `Vec<ContinuationPoint<T>>` : The root found at each parameter value, in order.
+
+Examples
+--------
+In this example, `continuation::track` follows the root of `2x - 3sin(x) + c` as `c` goes from 5 to 6, starting from the
+root of `trig` at c=5.
+```rust
+# use root_finding::*;
+let func = |x: f64, c: f64| 2.0 * x - 3.0 * x.sin() + c;
+let deriv = |x: f64, _c: f64| 2.0 - 3.0 * x.cos();
+let params: Vec<f64> = (0..=10).map(|i| 5.0 + i as f64 / 10.0).collect();
+let points = continuation::track(&func, &deriv, -2.88, &params, 1e-10, 100);
+let last = points.last().unwrap().result.as_ref().unwrap();
+assert!(func(last.root, 6.0).abs() < 1e-9);
+// Each Newton-Raphson step evaluates `F` and `∂F/∂x` once each.
+assert_eq!(2 * last.history.len(), last.evaluations);
+```
+---
+In this example, the roots of `x^2 + c` meet and vanish as `c` passes 0, so at `c = 1` both Newton-Raphson and the
+recovery fail, and the point records why.
+```rust
+# use root_finding::*;
+use root_finding::root_search::InvertError;
+let func = |x: f64, c: f64| x * x + c;
+let deriv = |x: f64, _c: f64| 2.0 * x;
+let points = continuation::track(&func, &deriv, 1.0, &[-1.0, 1.0], 1e-10, 100);
+assert!(points[0].result.is_ok());
+assert!(points[1].result.is_err());
+assert!(matches!(points[1].recovery_error, Some(InvertError::NoBracket { .. })));
+```
+*/
+pub fn track<T: Float>(
+    func: &dyn Fn(T, T) -> T,
+    deriv: &dyn Fn(T, T) -> T,
+    root: T,
+    params: &[T],
+    trunc_err: T,
+    max_iter: usize,
+) -> Vec<ContinuationPoint<T>> {
+    trace_span!("continuation::track");
+    let mut last_root: T = root;
+    params
+        .iter()
+        .map(|&param| {
+            let f = move |x: T| -> T { func(x, param) };
+            let df = move |x: T| -> T { deriv(x, param) };
+            // `fixed_point` counts each Newton-Raphson step as one evaluation, so `F` and `F'` are counted here.
+            let (counted_f, counted_df) = (CountedFunction::new(&f), CountedFunction::new(&df));
+            let (f, df) = (|x: T| counted_f.call(x), |x: T| counted_df.call(x));
+            let newton: BoxedFn<T> = functional::x_minus(functional::newton_raphson(f, df));
+            let mut point: ContinuationPoint<T> = match root_search::fixed_point(
+                &newton,
+                last_root,
+                trunc_err,
+                Budget::new(max_iter, None),
+            ) {
+                Ok(res) => ContinuationPoint {
+                    param,
+                    result: Ok(res),
+                    bisected: false,
+                    recovery_error: None,
+                },
+                Err(err) => match root_search::invert(
+                    &f,
+                    T::zero(),
+                    DomainOrGuess::Guess(last_root),
+                    trunc_err,
+                ) {
+                    Ok(res) => ContinuationPoint {
+                        param,
+                        result: Ok(res),
+                        bisected: true,
+                        recovery_error: None,
+                    },
+                    Err(recovery_error) => ContinuationPoint {
+                        param,
+                        result: Err(err),
+                        bisected: false,
+                        recovery_error: Some(recovery_error),
+                    },
+                },
+            };
+            let evaluations: usize = counted_f.evaluations() + counted_df.evaluations();
+            match point.result {
+                Ok(ref mut res) => {
+                    res.evaluations = evaluations;
+                    last_root = res.root;
+                }
+                Err(ref mut err) => err.evaluations = evaluations,
+            }
+            trace_debug!(
+                param = param.to_f64(),
+                root = last_root.to_f64(),
+                converged = point.result.is_ok(),
+                bisected = point.bisected
+            );
+            point
+        })
+        .collect()
+}
@@ -0,0 +1,89 @@
+/*!
+Export of root-finding results and iteration histories to CSV and JSON files, for plotting and analysis elsewhere.
+
+JSON export requires the `serde` feature.
+
+Functions
+---------
+* `history_to_csv` : Formats a sequence of iterates as CSV.
+* `write_history_csv` : Writes a sequence of iterates to a CSV file.
+
+`RootResult` also gains the methods `to_csv`, `write_csv`, and with the `serde` feature, `to_json` and `write_json`.
+*/
+
+use root_search::RootResult;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+use Float;
+
+/**
+Formats a sequence of iterates as CSV, with columns `n` and `x_n`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+assert_eq!("n,x_n\n0,2.5\n1,-0.8\n", export::history_to_csv(&[2.5, -0.8]));
+```
+*/
+pub fn history_to_csv<T: Float + Display>(history: &[T]) -> String {
+    let mut out: String = String::from("n,x_n\n");
+    for (n, x) in history.iter().enumerate() {
+        out.push_str(&format!("{},{}\n", n, x));
+    }
+    out
+}
+
+/// Writes a sequence of iterates to a CSV file at `path`, as formatted by `history_to_csv`.
+pub fn write_history_csv<T: Float + Display, P: AsRef<Path>>(
+    history: &[T],
+    path: P,
+) -> io::Result<()> {
+    fs::write(path, history_to_csv(history))
+}
+
+impl<T: Float + Display> RootResult<T> {
+    /**
+    Formats the history as CSV, with columns `n`, `x_n`, `error`, the signed distance of `x_n` from the root found, and
+    `residual`, which is empty for an iterate without one.
+
+    Examples
+    --------
+    ```rust
+    # use root_finding::*;
+    let res = root_search::RootResult { root: 1.0, history: vec![2.0, 1.5], residuals: vec![3.0], evaluations: 2 };
+    assert_eq!("n,x_n,error,residual\n0,2,1,3\n1,1.5,0.5,\n", res.to_csv());
+    ```
+    */
+    pub fn to_csv(&self) -> String {
+        let mut out: String = String::from("n,x_n,error,residual\n");
+        for (n, &x) in self.history.iter().enumerate() {
+            let residual: String = self
+                .residuals
+                .get(n)
+                .map_or_else(String::new, |r| r.to_string());
+            out.push_str(&format!("{},{},{},{}\n", n, x, x - self.root, residual));
+        }
+        out
+    }
+
+    /// Writes the history to a CSV file at `path`, as formatted by `to_csv`.
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Float + Display + ::serde::Serialize> RootResult<T> {
+    /// Serialises the result as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        ::serde_json::to_string_pretty(self).expect("Failed to serialise to JSON")
+    }
+
+    /// Writes the result to a JSON file at `path`, as formatted by `to_json`.
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
@@ -0,0 +1,396 @@
+/*!
+Functions of `x` parsed at runtime from strings, such as `"2*x - 3*sin(x) + 5"`, for ad-hoc experiments without
+adding to `test_function`.
+
+The grammar is the usual one for arithmetic: `+`, `-`, `*`, `/` and `^` (right associative, binding tighter than unary
+minus, so `-x^2` is `-(x^2)`), parentheses, numeric literals such as `2.5` and `1e-3`, the variable `x`, the
+constants `pi` and `e`, and the functions `sin`, `cos`, `tan`, `exp`, `ln`, `log10`, `sqrt` and `abs`.
+
+Expressions evaluate in any `num::Float`, so they can be run in single precision, or differentiated automatically by
+evaluating them on `autodiff::Dual`.
+
+Types
+-----
+* `Expr` : A parsed expression in `x`.
+* `ParseError` : Why an expression failed to parse, and where.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::error;
+use core::fmt;
+use core::str::FromStr;
+use BoxedFn;
+use Float;
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+/// A built-in function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Func {
+    Sin,
+    Cos,
+    Tan,
+    Exp,
+    Ln,
+    Log10,
+    Sqrt,
+    Abs,
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<Func> {
+        match name {
+            "sin" => Some(Func::Sin),
+            "cos" => Some(Func::Cos),
+            "tan" => Some(Func::Tan),
+            "exp" => Some(Func::Exp),
+            "ln" => Some(Func::Ln),
+            "log10" => Some(Func::Log10),
+            "sqrt" => Some(Func::Sqrt),
+            "abs" => Some(Func::Abs),
+            _ => None,
+        }
+    }
+
+    fn apply<T: Float>(self, x: T) -> T {
+        match self {
+            Func::Sin => x.sin(),
+            Func::Cos => x.cos(),
+            Func::Tan => x.tan(),
+            Func::Exp => x.exp(),
+            Func::Ln => x.ln(),
+            Func::Log10 => x.log10(),
+            Func::Sqrt => x.sqrt(),
+            Func::Abs => x.abs(),
+        }
+    }
+}
+
+/// A node of the syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Const(f64),
+    Var,
+    Neg(Box<Node>),
+    Binary(BinOp, Box<Node>, Box<Node>),
+    Call(Func, Box<Node>),
+}
+
+impl Node {
+    fn eval<T: Float>(&self, x: T) -> T {
+        match *self {
+            Node::Const(c) => T::from(c).unwrap(),
+            Node::Var => x,
+            Node::Neg(ref arg) => -arg.eval(x),
+            Node::Binary(op, ref lhs, ref rhs) => {
+                let lhs: T = lhs.eval(x);
+                match op {
+                    BinOp::Add => lhs + rhs.eval(x),
+                    BinOp::Sub => lhs - rhs.eval(x),
+                    BinOp::Mul => lhs * rhs.eval(x),
+                    BinOp::Div => lhs / rhs.eval(x),
+                    // Integer powers are computed by repeated multiplication, which is exact for e.g. `x^2` at
+                    // negative `x`, where `powf` would give NaN.
+                    BinOp::Pow => match **rhs {
+                        Node::Const(n) if n == n.round() && n.abs() <= i32::MAX as f64 => {
+                            lhs.powi(n as i32)
+                        }
+                        _ => lhs.powf(rhs.eval(x)),
+                    },
+                }
+            }
+            Node::Call(func, ref arg) => func.apply(arg.eval(x)),
+        }
+    }
+}
+
+/// Why an expression failed to parse, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset in the input at which the error was found.
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Error: {} at position {}!", self.message, self.position)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A token of the input, with its byte offset.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let bytes: &[u8] = input.as_bytes();
+    let mut tokens: Vec<(usize, Token)> = Vec::new();
+    let mut i: usize = 0;
+    while i < bytes.len() {
+        let c: char = bytes[i] as char;
+        let start: usize = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            while i < bytes.len() && ((bytes[i] as char).is_ascii_digit() || bytes[i] == b'.') {
+                i += 1;
+            }
+            // An exponent, e.g. `1e-3`, is only part of the number if digits follow.
+            if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                let mut j: usize = i + 1;
+                if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && (bytes[j] as char).is_ascii_digit() {
+                    i = j;
+                    while i < bytes.len() && (bytes[i] as char).is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let value: f64 = input[start..i].parse().map_err(|_| ParseError {
+                position: start,
+                message: "invalid number",
+            })?;
+            tokens.push((start, Token::Num(value)));
+        } else if c.is_ascii_alphabetic() {
+            while i < bytes.len() && (bytes[i] as char).is_ascii_alphanumeric() {
+                i += 1;
+            }
+            tokens.push((start, Token::Ident(input[start..i].to_string())));
+        } else {
+            let token: Token = match c {
+                '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                _ => {
+                    return Err(ParseError {
+                        position: start,
+                        message: "unexpected character",
+                    })
+                }
+            };
+            tokens.push((start, token));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// A recursive-descent parser over the tokens of an expression.
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    /// The byte offset of the next token, or of the end of the input.
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.end, |&(offset, _)| offset)
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError {
+            position: self.offset(),
+            message,
+        }
+    }
+
+    fn eat_op(&mut self, ops: &[char]) -> Option<char> {
+        match self.peek() {
+            Some(&Token::Op(c)) if ops.contains(&c) => {
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    /// `sum := product (('+' | '-') product)*`
+    fn sum(&mut self) -> Result<Node, ParseError> {
+        let mut lhs: Node = self.product()?;
+        while let Some(c) = self.eat_op(&['+', '-']) {
+            let op: BinOp = if c == '+' { BinOp::Add } else { BinOp::Sub };
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    /// `product := unary (('*' | '/') unary)*`
+    fn product(&mut self) -> Result<Node, ParseError> {
+        let mut lhs: Node = self.unary()?;
+        while let Some(c) = self.eat_op(&['*', '/']) {
+            let op: BinOp = if c == '*' { BinOp::Mul } else { BinOp::Div };
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    /// `unary := ('+' | '-') unary | power`
+    fn unary(&mut self) -> Result<Node, ParseError> {
+        match self.eat_op(&['+', '-']) {
+            Some('-') => Ok(Node::Neg(Box::new(self.unary()?))),
+            Some(_) => self.unary(),
+            None => self.power(),
+        }
+    }
+
+    /// `power := atom ('^' unary)?`
+    fn power(&mut self) -> Result<Node, ParseError> {
+        let base: Node = self.atom()?;
+        if self.eat_op(&['^']).is_some() {
+            Ok(Node::Binary(
+                BinOp::Pow,
+                Box::new(base),
+                Box::new(self.unary()?),
+            ))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// `atom := number | 'x' | 'pi' | 'e' | function '(' sum ')' | '(' sum ')'`
+    fn atom(&mut self) -> Result<Node, ParseError> {
+        let token: Token = match self.peek() {
+            Some(token) => token.clone(),
+            None => return Err(self.error("unexpected end of expression")),
+        };
+        let start: usize = self.offset();
+        self.pos += 1;
+        match token {
+            Token::Num(c) => Ok(Node::Const(c)),
+            Token::Ident(ref name) if name == "x" => Ok(Node::Var),
+            Token::Ident(ref name) if name == "pi" => Ok(Node::Const(core::f64::consts::PI)),
+            Token::Ident(ref name) if name == "e" => Ok(Node::Const(core::f64::consts::E)),
+            Token::Ident(ref name) => match Func::from_name(name) {
+                Some(func) => {
+                    if self.peek() != Some(&Token::LParen) {
+                        return Err(self.error("expected '(' after function name"));
+                    }
+                    self.pos += 1;
+                    let arg: Node = self.parenthesised()?;
+                    Ok(Node::Call(func, Box::new(arg)))
+                }
+                None => Err(ParseError {
+                    position: start,
+                    message: "unknown identifier",
+                }),
+            },
+            Token::LParen => self.parenthesised(),
+            Token::RParen | Token::Op(_) => Err(ParseError {
+                position: start,
+                message: "expected a number, variable or '('",
+            }),
+        }
+    }
+
+    /// The rest of a parenthesised expression, after the opening bracket.
+    fn parenthesised(&mut self) -> Result<Node, ParseError> {
+        let inner: Node = self.sum()?;
+        if self.peek() != Some(&Token::RParen) {
+            return Err(self.error("expected ')'"));
+        }
+        self.pos += 1;
+        Ok(inner)
+    }
+}
+
+/**
+A function of `x`, parsed from a string.
+
+Examples
+--------
+In this example, we parse `test_function::trig` and evaluate it at its root.
+```rust
+# use root_finding::*;
+let trig = expr::Expr::parse("2*x - 3*sin(x) + 5")?;
+assert_eq!(0.0, (trig.eval(-2.8832368725582835_f64)*10.0).round()/10.0);
+# Ok::<(), expr::ParseError>(())
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    root: Node,
+}
+
+impl Expr {
+    /**
+    Parses an expression in `x`.
+
+    Errors
+    ------
+    * `ParseError` : If `input` is not a valid expression, giving the position of the problem.
+    */
+    pub fn parse(input: &str) -> Result<Expr, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            pos: 0,
+            end: input.len(),
+        };
+        let root: Node = parser.sum()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(parser.error("unexpected token"));
+        }
+        Ok(Expr { root })
+    }
+
+    /// Evaluates the expression at `x`, in any floating-point type.
+    pub fn eval<T: Float>(&self, x: T) -> T {
+        self.root.eval(x)
+    }
+
+    /**
+    The expression as a `ContinuousFunction`, for passing to the root-finders and functionals.
+
+    Examples
+    --------
+    In this example, `root_search::binary` should find the root 0.6 ± 0.1 of `x - exp(-x)`.
+    ```rust
+    # use root_finding::*;
+    let func = expr::Expr::parse("x - exp(-x)")?;
+    let bracket = root_search::Bracket::new(&*func.function(), 0.0_f64, 1.0)?;
+    let res = root_search::binary(&*func.function(), bracket, 1e-10, root_search::Budget::unlimited())?;
+    assert_eq!(0.6, (res.result.root*10.0).round()/10.0);
+    # Ok::<(), Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn function<'a, T: Float + 'a>(&'a self) -> BoxedFn<'a, T> {
+        Box::new(move |x: T| -> T { self.eval(x) })
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Expr, ParseError> {
+        Expr::parse(input)
+    }
+}
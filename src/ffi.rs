@@ -0,0 +1,263 @@
+/*!
+C bindings, with the `ffi` feature, for calling the solvers from C, Fortran, or anything else with a C FFI.
+
+Functions are passed as a C function pointer together with an opaque `void *` context, which is handed back on every
+call, so that a simulation can search over a function of its own state without globals. Each entry point writes its
+result through an out-pointer and returns an `RfStatus`, zero (`RF_STATUS_OK` in C) on success. The header
+`include/root_finding.h` is generated from this module by [cbindgen](https://github.com/mozilla/cbindgen), using the
+settings in `cbindgen.toml`, and the shared library is built by asking for a `cdylib` explicitly:
+
+```sh
+cargo rustc --lib --release --features ffi --crate-type cdylib
+cbindgen --config cbindgen.toml --output include/root_finding.h
+```
+
+```c
+#include <math.h>
+#include "root_finding.h"
+
+double trig(double x, void *ctx) { return 2*x - 3*sin(x) + 5; }
+double trig_deriv(double x, void *ctx) { return 2 - 3*cos(x); }
+
+RfResult res;
+if (rf_newton(trig, trig_deriv, NULL, -4.0, 1e-12, 100, &res) == RF_STATUS_OK) {
+    printf("%f after %zu iterations\n", res.root, res.iterations);
+}
+```
+
+Functions
+---------
+* `rf_bisect` : Binary search, a.k.a interval bisection.
+* `rf_newton` : Newton-Raphson iteration.
+* `rf_fixed_point` : Fixed point iteration.
+* `rf_status_message` : A description of an `RfStatus`, as a C string.
+
+Types
+-----
+* `RfFunction` : A C function of a double, with an opaque context.
+* `RfStatus` : Whether a search succeeded, and if not why it stopped.
+* `RfResult` : The root found by a search, or the last iterate of a failed one.
+*/
+
+use functional;
+use root_search::{
+    self, Bracket, Budget, CountedFunction, RootError, RootResult, TerminationReason,
+};
+use std::os::raw::{c_char, c_void};
+use BoxedFn;
+use ContinuousFunction;
+
+/// A C function `double f(double x, void *ctx)`, called with the context passed to the solver.
+pub type RfFunction = Option<unsafe extern "C" fn(x: f64, ctx: *mut c_void) -> f64>;
+
+/// Whether a search succeeded, and if not why it stopped, as in `root_search::TerminationReason`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RfStatus {
+    /// The search found a root.
+    Ok = 0,
+    /// A function pointer or the out-pointer was null.
+    NullPointer = 1,
+    /// The function doesn't change sign over the interval given to `rf_bisect`.
+    InvalidBracket = 2,
+    /// The maximum number of iterations was reached.
+    MaxIterations = 3,
+    /// The iterates were growing without bound.
+    Diverged = 4,
+    /// The iterates entered a cycle.
+    Cycling = 5,
+    /// The function evaluated to NaN or infinity, at `RfResult::root`.
+    NonFinite = 6,
+    /// The budget of function evaluations or time was spent.
+    BudgetExhausted = 7,
+}
+
+impl From<TerminationReason> for RfStatus {
+    fn from(reason: TerminationReason) -> RfStatus {
+        match reason {
+            TerminationReason::MaxIterations => RfStatus::MaxIterations,
+            TerminationReason::BudgetExhausted => RfStatus::BudgetExhausted,
+            TerminationReason::Diverged => RfStatus::Diverged,
+            TerminationReason::Cycling { .. } => RfStatus::Cycling,
+            TerminationReason::NonFinite => RfStatus::NonFinite,
+        }
+    }
+}
+
+/**
+The outcome of a search.
+
+On success, `root` is the root found; on failure, it is the last iterate computed, or NaN if there were none. The
+iterates themselves aren't returned, to keep the struct free of allocations the caller would have to release.
+*/
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RfResult {
+    pub root: f64,
+    /// The number of iterations made, each evaluating the function at one iterate; for `rf_bisect`, at one midpoint.
+    pub iterations: usize,
+    /// The number of function evaluations made, including evaluations of the derivative.
+    pub evaluations: usize,
+}
+
+impl<'a> From<&'a RootResult> for RfResult {
+    fn from(res: &'a RootResult) -> RfResult {
+        RfResult {
+            root: res.root,
+            iterations: res.residuals.len(),
+            evaluations: res.evaluations,
+        }
+    }
+}
+
+impl<'a> From<&'a RootError> for RfResult {
+    fn from(err: &'a RootError) -> RfResult {
+        RfResult {
+            root: err.history.last().cloned().unwrap_or(f64::NAN),
+            iterations: err.residuals.len(),
+            evaluations: err.evaluations,
+        }
+    }
+}
+
+/// The final bracket of `rf_bisect`, which contains the root.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RfBracket {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+/// Writes the outcome of a search to `out`, returning its status.
+unsafe fn finish(res: Result<RootResult, RootError>, out: *mut RfResult) -> RfStatus {
+    match res {
+        Ok(res) => {
+            *out = RfResult::from(&res);
+            RfStatus::Ok
+        }
+        Err(err) => {
+            *out = RfResult::from(&err);
+            RfStatus::from(err.reason)
+        }
+    }
+}
+
+/**
+Binary search for a root of `func` over `[a, b]`, to within `tol`, making at most `max_iter` iterations.
+
+On success, the final bracket is also written to `bracket`, unless it is null.
+
+Safety
+------
+`func` must be safe to call with `ctx`, and `bracket` and `out` must be null or valid for writes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn rf_bisect(
+    func: RfFunction,
+    ctx: *mut c_void,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+    bracket: *mut RfBracket,
+    out: *mut RfResult,
+) -> RfStatus {
+    let func = match func {
+        Some(func) if !out.is_null() => func,
+        _ => return RfStatus::NullPointer,
+    };
+    let call: &ContinuousFunction = &|x: f64| func(x, ctx);
+    let initial: Bracket = match Bracket::new(call, a, b) {
+        Ok(initial) => initial,
+        Err(_) => return RfStatus::InvalidBracket,
+    };
+    let res = root_search::binary(call, initial, tol, Budget::new(max_iter, None));
+    if let (Ok(res), false) = (&res, bracket.is_null()) {
+        *bracket = RfBracket {
+            lo: res.bracket.lo(),
+            hi: res.bracket.hi(),
+        };
+    }
+    finish(res.map(|res| res.result), out)
+}
+
+/**
+Newton-Raphson iteration on `func`, with derivative `deriv`, from `x0` until successive iterates are within `tol`.
+
+Safety
+------
+`func` and `deriv` must be safe to call with `ctx`, and `out` must be null or valid for writes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn rf_newton(
+    func: RfFunction,
+    deriv: RfFunction,
+    ctx: *mut c_void,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+    out: *mut RfResult,
+) -> RfStatus {
+    let (func, deriv) = match (func, deriv) {
+        (Some(func), Some(deriv)) if !out.is_null() => (func, deriv),
+        _ => return RfStatus::NullPointer,
+    };
+    let (call, call_deriv) = (|x: f64| func(x, ctx), |x: f64| deriv(x, ctx));
+    let (func, deriv) = (
+        CountedFunction::new(&call),
+        CountedFunction::new(&call_deriv),
+    );
+    let (call, call_deriv) = (|x: f64| func.call(x), |x: f64| deriv.call(x));
+    let newton: BoxedFn = functional::x_minus(functional::newton_raphson(call, call_deriv));
+    let status: RfStatus = finish(
+        root_search::fixed_point(&newton, x0, tol, Budget::new(max_iter, None)),
+        out,
+    );
+    // `fixed_point` counts the Newton-Raphson steps, each of which evaluates the derivative, and the function unless
+    // the derivative is 0.
+    (*out).evaluations = func.evaluations() + deriv.evaluations();
+    status
+}
+
+/**
+Fixed point iteration of `func` from `x0` until successive iterates are within `tol`.
+
+Safety
+------
+`func` must be safe to call with `ctx`, and `out` must be null or valid for writes.
+*/
+#[no_mangle]
+pub unsafe extern "C" fn rf_fixed_point(
+    func: RfFunction,
+    ctx: *mut c_void,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+    out: *mut RfResult,
+) -> RfStatus {
+    let func = match func {
+        Some(func) if !out.is_null() => func,
+        _ => return RfStatus::NullPointer,
+    };
+    let call: &ContinuousFunction = &|x: f64| func(x, ctx);
+    finish(
+        root_search::fixed_point(call, x0, tol, Budget::new(max_iter, None)),
+        out,
+    )
+}
+
+/// A description of `status`, as a static NUL-terminated string, which the caller must not free.
+#[no_mangle]
+pub extern "C" fn rf_status_message(status: RfStatus) -> *const c_char {
+    let message: &'static [u8] = match status {
+        RfStatus::Ok => b"ok\0",
+        RfStatus::NullPointer => b"Error: null pointer!\0",
+        RfStatus::InvalidBracket => b"Error: no sign change at endpoints!\0",
+        RfStatus::MaxIterations => b"Error: failed to converge!\0",
+        RfStatus::Diverged => b"Error: iterates diverged!\0",
+        RfStatus::Cycling => b"Error: iterates entered a cycle!\0",
+        RfStatus::NonFinite => b"Error: function is not finite!\0",
+        RfStatus::BudgetExhausted => b"Error: budget exhausted!\0",
+    };
+    message.as_ptr() as *const c_char
+}
@@ -6,10 +6,56 @@ Functionals
 * `x_minus` : id-F.
 * `identity` : F
 * `frac` : F/(2+k) unless k=-2, in which case returns the constant function 1; since x -> x-1 has no fixed point, this behaviour is fine and avoids complicated return types.
+* `compose` : F∘G.
+* `scale` : cF.
+* `shift` : F+c.
+* `negate` : -F.
+* `affine` : aF+b.
 * `newton_raphson` : F/F'
+* `newton_autodiff` : F/F', with F' computed by automatic differentiation.
+* `numerical_derivative` : F', approximated by finite differences.
+* `newton_numerical` : F/F', with F' approximated by finite differences.
+* `complex_step_derivative` : F', computed by the complex-step method.
+* `newton_complex_step` : F/F', with F' computed by the complex-step method.
+* `householder` : The step of Householder's method of any order, from F and its derivatives; order 1 is `newton_raphson`.
+* `memoize` : F, caching its values.
+
+All functionals are generic over `num::Float`. Those built from arithmetic alone (`x_minus`, `identity`, `compose`,
+`scale`, `shift`, `negate`, `affine` and `newton_raphson`) only need `num::Num`, so apply to complex functions too,
+e.g. to build the Newton-Raphson map of a polynomial with complex roots, for `root_search::newton_complex`.
+
+Ownership
+---------
+Every functional takes its functions by value, as any `ObjectiveFn`, and returns an owned `BoxedFn`. A function can be
+passed owned, e.g. a closure or the result of another functional, or borrowed, as `&f`, so transforms chain freely,
+e.g. `x_minus(scale(identity(&f), c))`, including over closures capturing local variables.
+
+```rust
+# use root_finding::*;
+let c: f64 = 0.5;
+let shifted = |x: f64| x.cos() - c;
+let newton = functional::x_minus(functional::newton_raphson(shifted, |x: f64| -x.sin()));
+let res = root_search::fixed_point(&newton, 1.0, 1e-12, root_search::Budget::new(100, None))?;
+assert!((res.root - c.acos()).abs() < 1e-12);
+# Ok::<(), root_search::RootError>(())
+```
 */
 
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use autodiff::Dual;
+use core::cell::RefCell;
+use core::ops::Neg;
+use num::{Complex, Num};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use BoxedFn;
 use ContinuousFunction;
+use Float;
+use ObjectiveFn;
 
 /**
 Inverts a function, i.e. applies the transform `f(x) -> 1-f(x)`.
@@ -20,18 +66,19 @@ Parameters
 
 Returns
 -------
-* `ContinuousFunction` : The inversion of `func`. 
+* `ContinuousFunction` : The inversion of `func`.
 
 Examples
 --------
 In this example, `functional::x_minus` should return f(x) = x-cos(x), which we test at x=pi.
 ```rust
+# use root_finding::*;
 let res:f64 = functional::x_minus(Box::new(|x:f64| -> f64 {x.cos()}))(3.1415);
 assert_eq!(4.1, (res*10.0).round()/10.0);
 ```
 */
-pub fn x_minus<'a>(func: Box<ContinuousFunction<'a>>) -> Box<ContinuousFunction<'a>> {
-    Box::new(move |x: f64| -> f64 { x - func(x) })
+pub fn x_minus<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a>(func: F) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { x - func.eval(x) })
 }
 
 /**
@@ -43,18 +90,19 @@ Parameters
 
 Returns
 -------
-* `ContinuousFunction` : The inversion of `func`. 
+* `ContinuousFunction` : The inversion of `func`.
 
 Examples
 --------
 In this example, `functional::identity` should return f(x) = cos(x), which we test at x=pi.
 ```rust
+# use root_finding::*;
 let res:f64 = functional::identity(&(|x:f64| -> f64 {x.cos()}))(3.1415);
 assert_eq!(-1.0, (res*10.0).round()/10.0);
 ```
 */
-pub fn identity<'a>(func: &'a ContinuousFunction) -> Box<ContinuousFunction<'a>> {
-    Box::new(func)
+pub fn identity<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a>(func: F) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { func.eval(x) })
 }
 
 /**
@@ -72,18 +120,161 @@ Examples
 --------
 In this example, `functional::identity` should return f(x) = cos(x), which we test at x=pi.
 ```rust
+# use root_finding::*;
 let res:f64 = functional::identity(&(|x:f64| -> f64 {x.cos()}))(3.1415);
 assert_eq!(-1.0, (res*10.0).round()/10.0);
 ```
 */
-pub fn frac<'a, 'b: 'a>(func: &'a ContinuousFunction, k: &'b f64) -> Box<ContinuousFunction<'a>> {
-    if *k == -2.0 {
-        Box::new(|_x: f64| -> f64 { 1.0 })
+pub fn frac<'a, 'b: 'a, T: Float + 'a, F: ObjectiveFn<T> + 'a>(
+    func: F,
+    k: &'b T,
+) -> BoxedFn<'a, T> {
+    let two: T = T::one() + T::one();
+    if *k == -two {
+        Box::new(|_x: T| -> T { T::one() })
     } else {
-        Box::new(move |x: f64| -> f64 { &func(x) / (2.0 + *k) })
+        Box::new(move |x: T| -> T { func.eval(x) / (two + *k) })
     }
 }
 
+/**
+Composes two functions, i.e. applies the transform `(f, g) -> f(g(x))`.
+
+Parameters
+----------
+* `outer` : A continuous function, applied second.
+* `inner` : A continuous function, applied first.
+
+Returns
+-------
+* `ContinuousFunction` : The composition of `outer` with `inner`.
+
+Examples
+--------
+In this example, `functional::compose` should return f(x) = cos(x^2), which we test at x=0.
+```rust
+# use root_finding::*;
+let res:f64 = functional::compose(Box::new(|x:f64| x.cos()), Box::new(|x:f64| x * x))(0.0);
+assert_eq!(1.0, res);
+```
+*/
+pub fn compose<'a, T: 'a, F: ObjectiveFn<T> + 'a, G: ObjectiveFn<T> + 'a>(
+    outer: F,
+    inner: G,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { outer.eval(inner.eval(x)) })
+}
+
+/**
+Scales a function, i.e. applies the transform `f(x) -> cf(x)`.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `c` : The scale factor.
+
+Returns
+-------
+* `ContinuousFunction` : `func` multiplied by `c`.
+
+Examples
+--------
+In this example, the relaxation scheme `g(x) = x - F(x)/(2+k)` is built from `functional::scale`, and should have the
+same fixed point as `functional::frac`, the root of `trig`.
+```rust
+# use root_finding::*;
+let k: f64 = 2.0;
+let g = functional::x_minus(functional::scale(functional::identity(&test_function::trig), 1.0 / (2.0 + k)));
+let res = root_search::fixed_point(&g, -2.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(-2.9, (res.root*10.0).round()/10.0);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn scale<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a>(func: F, c: T) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { c * func.eval(x) })
+}
+
+/**
+Shifts a function, i.e. applies the transform `f(x) -> f(x)+c`, e.g. to solve `F(x) = -c`.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `c` : The amount to shift by.
+
+Returns
+-------
+* `ContinuousFunction` : `func` plus `c`.
+
+Examples
+--------
+In this example, `functional::shift` should return f(x) = cos(x) - 1, which we test at x=0.
+```rust
+# use root_finding::*;
+let res:f64 = functional::shift(Box::new(|x:f64| x.cos()), -1.0)(0.0);
+assert_eq!(0.0, res);
+```
+*/
+pub fn shift<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a>(func: F, c: T) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { func.eval(x) + c })
+}
+
+/**
+Negates a function, i.e. applies the transform `f(x) -> -f(x)`.
+
+Parameters
+----------
+* `func` : A continuous function.
+
+Returns
+-------
+* `ContinuousFunction` : The negation of `func`.
+
+Examples
+--------
+In this example, `functional::negate` should return f(x) = -cos(x), which we test at x=0.
+```rust
+# use root_finding::*;
+let res:f64 = functional::negate(Box::new(|x:f64| x.cos()))(0.0);
+assert_eq!(-1.0, res);
+```
+*/
+pub fn negate<'a, T: Num + Neg<Output = T> + Copy + 'a, F: ObjectiveFn<T> + 'a>(
+    func: F,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { -func.eval(x) })
+}
+
+/**
+Applies an affine transform to the values of a function, i.e. the transform `f(x) -> af(x)+b`.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `a` : The scale factor.
+* `b` : The amount to shift by, after scaling.
+
+Returns
+-------
+* `ContinuousFunction` : `func` multiplied by `a`, plus `b`.
+
+Examples
+--------
+In this example, `functional::affine` should return f(x) = 2cos(x) + 1, which we test at x=0.
+```rust
+# use root_finding::*;
+let res:f64 = functional::affine(Box::new(|x:f64| x.cos()), 2.0, 1.0)(0.0);
+assert_eq!(3.0, res);
+```
+*/
+pub fn affine<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a>(
+    func: F,
+    a: T,
+    b: T,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { a * func.eval(x) + b })
+}
+
 /**
 Applies the Newton-Raphson transform to a function.
 
@@ -100,19 +291,402 @@ Examples
 --------
 In this example, `functional::newton_raphson` should return f(x) = tan(x), which we test at x=pi/4.
 ```rust
+# use root_finding::*;
 let res:f64 = functional::newton_raphson(&(|x:f64| -> f64 {x.sin()}), &(|x:f64| -> f64 {x.cos()}))(3.1415/4.0);
 assert_eq!(1.0, (res*10.0).round()/10.0);
 ```
 */
-pub fn newton_raphson<'a, 'b: 'a>(
-    func: &'a ContinuousFunction,
-    deriv: &'b ContinuousFunction,
-) -> Box<ContinuousFunction<'a>> {
+pub fn newton_raphson<'a, T: Num + Copy + 'a, F: ObjectiveFn<T> + 'a, D: ObjectiveFn<T> + 'a>(
+    func: F,
+    deriv: D,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T {
+        let d: T = deriv.eval(x);
+        if d.is_zero() {
+            T::one()
+        } else {
+            func.eval(x) / d
+        }
+    })
+}
+
+/**
+Applies the Newton-Raphson transform to a function, computing the derivative automatically.
+
+Parameters
+----------
+* `func` : A differentiable function of a dual number, e.g. any function generic over `num::Float`.
+
+Returns
+-------
+* `ContinuousFunction` : Input function divided by its derivative, except where the derivative is 0.
+
+Examples
+--------
+In this example, `functional::newton_autodiff` should return f(x) = tan(x), which we test at x=pi/4.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use num::Float;
+# use root_finding::*;
+let res:f64 = functional::newton_autodiff(&(|x:autodiff::Dual| -> autodiff::Dual {x.sin()}))(3.1415/4.0);
+assert_eq!(1.0, (res*10.0).round()/10.0);
+```
+*/
+pub fn newton_autodiff<'a, F: ObjectiveFn<Dual> + 'a>(func: F) -> BoxedFn<'a> {
     Box::new(move |x: f64| -> f64 {
-        if deriv(x) == 0.0 {
+        let res: Dual = func.eval(Dual::variable(x));
+        if res.deriv == 0.0 {
             1.0
         } else {
-            &func(x) / deriv(x)
+            res.val / res.deriv
+        }
+    })
+}
+
+/// A finite-difference scheme for approximating derivatives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DifferenceScheme {
+    /// `(F(x+h) - F(x))/h`, with error `O(h)`.
+    Forward,
+    /// `(F(x+h) - F(x-h))/2h`, with error `O(h^2)`.
+    Central,
+    /// `(-F(x+2h) + 8F(x+h) - 8F(x-h) + F(x-2h))/12h`, with error `O(h^4)`.
+    FivePoint,
+}
+
+impl DifferenceScheme {
+    /// The order of the truncation error of the scheme.
+    pub fn order(&self) -> i32 {
+        match self {
+            DifferenceScheme::Forward => 1,
+            DifferenceScheme::Central => 2,
+            DifferenceScheme::FivePoint => 4,
+        }
+    }
+
+    /**
+    A step size at `x` balancing truncation error against rounding error.
+
+    For a scheme of order `n`, the total error is roughly `h^n + eps/h`, which is minimised at `h ~ eps^(1/(n+1))`.
+    This is scaled by `|x|` away from the origin, so that `x+h` differs from `x` in a fixed number of bits.
+    */
+    pub fn step_size<T: Float>(&self, x: T) -> T {
+        let exponent: T = T::one() / T::from(self.order() + 1).unwrap();
+        T::epsilon().powf(exponent) * x.abs().max(T::one())
+    }
+}
+
+/**
+Approximates the derivative of a function by finite differences.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `scheme` : The finite-difference scheme to use.
+* `h` : The step size. If `None`, a step size is chosen automatically at each point by `DifferenceScheme::step_size`.
+
+Returns
+-------
+* `ContinuousFunction` : An approximation to the derivative of `func`.
+
+Examples
+--------
+In this example, `functional::numerical_derivative` should return f(x) = cos(x), which we test at x=pi.
+```rust
+# use root_finding::*;
+let res:f64 = functional::numerical_derivative(&(|x:f64| -> f64 {x.sin()}), functional::DifferenceScheme::Central, None)(3.1415);
+assert_eq!(-1.0, (res*10.0).round()/10.0);
+```
+*/
+pub fn numerical_derivative<'a, T: Float + 'a, F: ObjectiveFn<T> + 'a>(
+    func: F,
+    scheme: DifferenceScheme,
+    h: Option<T>,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { difference(&func, scheme, h, x) })
+}
+
+/// The finite-difference approximation to the derivative of `func` at `x`, as computed by `numerical_derivative`.
+fn difference<T: Float, F: ObjectiveFn<T>>(
+    func: &F,
+    scheme: DifferenceScheme,
+    h: Option<T>,
+    x: T,
+) -> T {
+    let h: T = h.unwrap_or_else(|| scheme.step_size(x));
+    let two: T = T::one() + T::one();
+    match scheme {
+        DifferenceScheme::Forward => (func.eval(x + h) - func.eval(x)) / h,
+        DifferenceScheme::Central => (func.eval(x + h) - func.eval(x - h)) / (two * h),
+        DifferenceScheme::FivePoint => {
+            let eight: T = T::from(8.0).unwrap();
+            let twelve: T = T::from(12.0).unwrap();
+            (func.eval(x - two * h) - eight * func.eval(x - h) + eight * func.eval(x + h)
+                - func.eval(x + two * h))
+                / (twelve * h)
+        }
+    }
+}
+
+/**
+Applies the Newton-Raphson transform to a function, approximating the derivative by finite differences.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `scheme` : The finite-difference scheme to use.
+* `h` : The step size. If `None`, a step size is chosen automatically at each point.
+
+Returns
+-------
+* `ContinuousFunction` : Input function divided by its approximate derivative, except where that is 0.
+
+Examples
+--------
+In this example, `functional::newton_numerical` should return f(x) = tan(x), which we test at x=pi/4.
+```rust
+# use root_finding::*;
+let res:f64 = functional::newton_numerical(&(|x:f64| -> f64 {x.sin()}), functional::DifferenceScheme::Forward, None)(3.1415/4.0);
+assert_eq!(1.0, (res*10.0).round()/10.0);
+```
+*/
+pub fn newton_numerical<'a, T: Float + 'a, F: ObjectiveFn<T> + 'a>(
+    func: F,
+    scheme: DifferenceScheme,
+    h: Option<T>,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T {
+        let deriv_val: T = difference(&func, scheme, h, x);
+        if deriv_val.is_zero() {
+            T::one()
+        } else {
+            func.eval(x) / deriv_val
+        }
+    })
+}
+
+/**
+Computes the derivative of a function by the complex-step method, `F'(x) = Im(F(x+ih))/h + O(h^2)`.
+
+Unlike finite differences there is no subtractive cancellation, so `h` can be taken tiny and the derivative is accurate
+to machine precision. `func` must be real on the real axis and analytic near it.
+
+Parameters
+----------
+* `func` : An analytic function, extended to the complex plane.
+* `h` : The step size. If `None`, a step of `epsilon*max(|x|,1)` is used, which is small enough that the truncation error is negligible.
+
+Returns
+-------
+* `ContinuousFunction` : The derivative of `func` along the real axis.
+
+Examples
+--------
+In this example, `functional::complex_step_derivative` should return f(x) = cos(x), which we test at x=pi.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use num::Complex;
+# use root_finding::*;
+let res:f64 = functional::complex_step_derivative(&(|z:Complex<f64>| -> Complex<f64> {z.sin()}), None)(3.1415);
+assert_eq!(-1.0, (res*10.0).round()/10.0);
+```
+*/
+pub fn complex_step_derivative<'a, T: Float + 'a, F: ObjectiveFn<Complex<T>> + 'a>(
+    func: F,
+    h: Option<T>,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T { complex_step(&func, h, x) })
+}
+
+/// The complex-step derivative of `func` at `x`, as computed by `complex_step_derivative`.
+fn complex_step<T: Float, F: ObjectiveFn<Complex<T>>>(func: &F, h: Option<T>, x: T) -> T {
+    let h: T = h.unwrap_or_else(|| T::epsilon() * x.abs().max(T::one()));
+    func.eval(Complex::new(x, h)).im / h
+}
+
+/**
+Applies the Newton-Raphson transform to a function, computing the derivative by the complex-step method.
+
+Parameters
+----------
+* `func` : An analytic function, extended to the complex plane.
+* `h` : The step size. If `None`, a step size is chosen automatically at each point.
+
+Returns
+-------
+* `ContinuousFunction` : Input function divided by its derivative, except where the derivative is 0.
+
+Examples
+--------
+In this example, `functional::newton_complex_step` should return f(x) = tan(x), which we test at x=pi/4.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use num::Complex;
+# use root_finding::*;
+let res:f64 = functional::newton_complex_step(&(|z:Complex<f64>| -> Complex<f64> {z.sin()}), None)(3.1415/4.0);
+assert_eq!(1.0, (res*10.0).round()/10.0);
+```
+*/
+pub fn newton_complex_step<'a, T: Float + 'a, F: ObjectiveFn<Complex<T>> + 'a>(
+    func: F,
+    h: Option<T>,
+) -> BoxedFn<'a, T> {
+    Box::new(move |x: T| -> T {
+        let deriv_val: T = complex_step(&func, h, x);
+        if deriv_val.is_zero() {
+            T::one()
+        } else {
+            func.eval(Complex::new(x, T::zero())).re / deriv_val
+        }
+    })
+}
+
+/**
+Applies the Householder transform of order `d` to a function, for Householder's method `x -> x + d(1/F)^(d-1)/(1/F)^(d)`.
+
+Householder's method of order `d` converges with order `d+1` at a simple root, using the first `d` derivatives of `F`:
+order 1 is Newton-Raphson, and order 2 is Halley's method. The derivatives of `1/F` are found from those of `F` by
+inverting its Taylor series: with `a_k = F^(k)(x)/k!`, the coefficients `b_k` of `1/F` satisfy `a_0 b_0 = 1` and
+`a_0 b_n = -(a_1 b_(n-1) + ... + a_n b_0)`, and the step is `b_(d-1)/b_d`. Each step evaluates all `d+1` functions,
+so higher orders trade fewer iterations for more work in each.
+
+Parameters
+----------
+* `derivs` : The function and its derivatives, `[F, F', F'', ...]`, at least `order + 1` of them.
+* `order` : The order `d` of the method, at least 1.
+
+Returns
+-------
+* `ContinuousFunction` : The Householder step `-b_(d-1)/b_d`, so that `x_minus` of it is the iteration, as `newton_raphson` is for
+  Newton-Raphson; or 0 where `F` is 0, and 1 where the step is undefined.
+
+Panics
+------
+* If `order` is 0, or fewer than `order + 1` functions are given.
+
+Examples
+--------
+In this example, Halley's method (order 2) finds the root of `trig` in fewer iterations than Newton-Raphson (order 1).
+```rust
+# use root_finding::*;
+let derivs: [&ContinuousFunction; 3] = [&test_function::trig, &test_func_derivative::trig, &test_func_second_derivative::trig];
+let newton = functional::x_minus(functional::householder(&derivs, 1));
+let halley = functional::x_minus(functional::householder(&derivs, 2));
+let budget = root_search::Budget::new(100, None);
+let newton = root_search::fixed_point(&newton, -3.0, 1e-12, budget)?;
+let halley = root_search::fixed_point(&halley, -3.0, 1e-12, budget)?;
+assert!((halley.root + 2.8832368725582835).abs() < 1e-12);
+assert!(halley.history.len() < newton.history.len());
+# Ok::<(), root_search::RootError>(())
+```
+---
+In this example, the order `d` iteration on `x^3 - 2x - 5`, whose derivatives beyond the third vanish, is checked
+empirically to converge with order about `d+1`.
+```rust
+# use root_finding::*;
+let derivs: [&ContinuousFunction; 5] = [
+    &test_function::newton_cubic,
+    &test_func_derivative::newton_cubic,
+    &test_func_second_derivative::newton_cubic,
+    &|_x: f64| 6.0,
+    &|_x: f64| 0.0,
+];
+let root: f64 = 2.0945514815423265;
+for order in 1..=2 {
+    let g = functional::x_minus(functional::householder(&derivs, order));
+    let res = root_search::fixed_point(&g, 3.0, 1e-15, root_search::Budget::new(100, None))?;
+    let report = analysis::estimate_order(&res.history, root);
+    assert_eq!((order + 1) as f64, report.order.unwrap().round());
+}
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn householder<'a, T: Float + 'a>(
+    derivs: &'a [&'a ContinuousFunction<'a, T>],
+    order: usize,
+) -> BoxedFn<'a, T> {
+    assert!(
+        order >= 1 && derivs.len() > order,
+        "Householder's method of order {} needs {} functions, but {} were given",
+        order,
+        order + 1,
+        derivs.len()
+    );
+    Box::new(move |x: T| -> T {
+        // The Taylor coefficients `a_k` of `F` at `x`, and `b_k` of `1/F`.
+        let mut factorial: T = T::one();
+        let a: Vec<T> = derivs[..=order]
+            .iter()
+            .enumerate()
+            .map(|(k, deriv)| {
+                if k > 0 {
+                    factorial = factorial * T::from(k).unwrap();
+                }
+                deriv(x) / factorial
+            })
+            .collect();
+        if a[0].is_zero() {
+            return T::zero();
+        }
+        let mut b: Vec<T> = vec![T::one() / a[0]];
+        for n in 1..=order {
+            let sum: T = (1..=n).fold(T::zero(), |sum, k| sum + a[k] * b[n - k]);
+            b.push(-sum / a[0]);
+        }
+        let step: T = -b[order - 1] / b[order];
+        if step.is_finite() {
+            step
+        } else {
+            T::one()
+        }
+    })
+}
+
+/**
+Caches the values of a function, so that repeated evaluations at the same point are only computed once.
+
+Points are keyed on their bit pattern, as given by `Float::integer_decode`, so `0.0` and `-0.0` are cached separately,
+and a point is only served from the cache if it is identical to one already evaluated. The cache is never evicted, so this suits
+expensive functions evaluated at a modest number of points, such as in a single root search.
+
+Parameters
+----------
+* `func` : A continuous function.
+
+Returns
+-------
+* `ContinuousFunction` : `func`, computing each distinct value only once.
+
+Examples
+--------
+In this example, the same Newton-Raphson search is run twice, but with `functional::memoize` the (counted) derivative
+of `trig` is only computed during the first.
+```rust
+# use root_finding::*;
+let deriv = root_search::CountedFunction::new(&test_func_derivative::trig);
+let counted = |x: f64| deriv.call(x);
+let memoized = functional::memoize(&counted);
+let newton = functional::x_minus(functional::newton_raphson(&test_function::trig, &*memoized));
+let res = root_search::fixed_point(&newton, -3.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(res.evaluations, deriv.evaluations());
+root_search::fixed_point(&newton, -3.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(res.evaluations, deriv.evaluations());
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn memoize<'a, T: Float + 'a, F: ObjectiveFn<T> + 'a>(func: F) -> BoxedFn<'a, T> {
+    let cache: RefCell<BTreeMap<(u64, i16, i8), T>> = RefCell::new(BTreeMap::new());
+    Box::new(move |x: T| -> T {
+        let key: (u64, i16, i8) = x.integer_decode();
+        if let Some(&val) = cache.borrow().get(&key) {
+            return val;
         }
+        let val: T = func.eval(x);
+        cache.borrow_mut().insert(key, val);
+        val
     })
 }
@@ -0,0 +1,264 @@
+/*!
+Interval arithmetic with outward rounding.
+
+An `Interval` `[lo, hi]` represents an unknown real number known to lie between `lo` and `hi`. Every operation returns
+an interval guaranteed to contain every possible result: after each floating-point operation the lower bound is
+rounded down and the upper bound rounded up by one unit in the last place, which accounts for the rounding error of
+both the arithmetic operations and (on the assumption that they are faithfully rounded) the elementary functions.
+
+Evaluating a function over intervals therefore bounds the rounding error rigorously, which `root_search::binary_verified`
+uses to return an interval certainly containing a root.
+
+Only available with the `interval` feature.
+
+Types
+-----
+* `Interval` : A closed interval of reals.
+*/
+
+use core::f64::consts::{FRAC_PI_2, PI};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+// `core` has no `f64::exp`, `f64::sin` etc., so without `std` they come from `Float`.
+#[cfg(not(feature = "std"))]
+use Float;
+
+/**
+A closed interval `[lo, hi]` of real numbers.
+
+Examples
+--------
+In this example, we bound 0.1 + 0.2, which rounds to 0.30000000000000004 in floating point.
+```rust
+# use root_finding::*;
+let x = interval::Interval::point(0.1) + interval::Interval::point(0.2);
+assert!(x.contains(0.3) && x.contains(0.1 + 0.2));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    /// Creates the interval between `a` and `b`, in either order.
+    pub fn new(a: f64, b: f64) -> Interval {
+        if a <= b {
+            Interval { lo: a, hi: b }
+        } else {
+            Interval { lo: b, hi: a }
+        }
+    }
+
+    /// The degenerate interval `[x, x]`, for a number known exactly.
+    pub fn point(x: f64) -> Interval {
+        Interval { lo: x, hi: x }
+    }
+
+    /// The whole real line, the result of e.g. dividing by an interval containing 0.
+    pub fn entire() -> Interval {
+        Interval {
+            lo: f64::NEG_INFINITY,
+            hi: f64::INFINITY,
+        }
+    }
+
+    /// Widens an interval computed with round-to-nearest outwards by one unit in the last place.
+    fn outward(lo: f64, hi: f64) -> Interval {
+        Interval {
+            lo: -next_up(-lo),
+            hi: next_up(hi),
+        }
+    }
+
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Whether every element of the interval is strictly positive.
+    pub fn is_positive(&self) -> bool {
+        self.lo > 0.0
+    }
+
+    /// Whether every element of the interval is strictly negative.
+    pub fn is_negative(&self) -> bool {
+        self.hi < 0.0
+    }
+
+    /// The interval of absolute values.
+    pub fn abs(self) -> Interval {
+        if self.lo >= 0.0 {
+            self
+        } else if self.hi <= 0.0 {
+            -self
+        } else {
+            Interval {
+                lo: 0.0,
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+
+    /// Raises the interval to an integer power, using only rigorous multiplications.
+    pub fn powi(self, n: i32) -> Interval {
+        if n < 0 {
+            return Interval::point(1.0) / self.powi(-n);
+        }
+        if n == 0 {
+            return Interval::point(1.0);
+        }
+        // x^n is monotonic on the non-negative reals, and for odd n on the whole line.
+        let base: Interval = if n % 2 == 0 { self.abs() } else { self };
+        let power = |x: f64| -> Interval { (1..n).fold(Interval::point(x), |acc, _| acc * x) };
+        Interval {
+            lo: power(base.lo).lo,
+            hi: power(base.hi).hi,
+        }
+    }
+
+    pub fn exp(self) -> Interval {
+        let res: Interval = Interval::outward(self.lo.exp(), self.hi.exp());
+        Interval {
+            lo: res.lo.max(0.0),
+            hi: res.hi,
+        }
+    }
+
+    pub fn sin(self) -> Interval {
+        if self.width() >= 2.0 * PI || !self.width().is_finite() {
+            return Interval::new(-1.0, 1.0);
+        }
+        // Including an extremum that isn't really in the interval only loosens the bound, so test generously.
+        let slack: f64 = 4.0 * f64::EPSILON * self.lo.abs().max(self.hi.abs()).max(1.0);
+        let attains = |peak: f64| -> bool {
+            let k: f64 = ((self.lo - slack - peak) / (2.0 * PI)).ceil();
+            peak + 2.0 * PI * k <= self.hi + slack
+        };
+
+        let (sin_lo, sin_hi): (f64, f64) = (self.lo.sin(), self.hi.sin());
+        let res: Interval = Interval::outward(sin_lo.min(sin_hi), sin_lo.max(sin_hi));
+        Interval {
+            lo: if attains(-FRAC_PI_2) {
+                -1.0
+            } else {
+                res.lo.max(-1.0)
+            },
+            hi: if attains(FRAC_PI_2) {
+                1.0
+            } else {
+                res.hi.min(1.0)
+            },
+        }
+    }
+
+    pub fn cos(self) -> Interval {
+        // cos(x) = sin(x + pi/2), where pi/2 itself must be enclosed since it isn't representable.
+        (self + Interval::outward(FRAC_PI_2, FRAC_PI_2)).sin()
+    }
+}
+
+/// The least `f64` greater than `x`, as `f64::next_up`, which needs Rust 1.86.
+fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+    fn mul(self, rhs: Interval) -> Interval {
+        let products: [f64; 4] = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo: f64 = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi: f64 = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+    fn div(self, rhs: Interval) -> Interval {
+        if rhs.contains(0.0) {
+            return Interval::entire();
+        }
+        let recip: Interval = Interval::outward(1.0 / rhs.hi, 1.0 / rhs.lo);
+        self * recip
+    }
+}
+
+// Mixed arithmetic with `f64`, treating the `f64` as known exactly.
+macro_rules! impl_f64_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait<f64> for Interval {
+            type Output = Interval;
+            fn $method(self, rhs: f64) -> Interval {
+                self.$method(Interval::point(rhs))
+            }
+        }
+
+        impl $trait<Interval> for f64 {
+            type Output = Interval;
+            fn $method(self, rhs: Interval) -> Interval {
+                Interval::point(self).$method(rhs)
+            }
+        }
+    };
+}
+
+impl_f64_op!(Add, add);
+impl_f64_op!(Sub, sub);
+impl_f64_op!(Mul, mul);
+impl_f64_op!(Div, div);
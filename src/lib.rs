@@ -0,0 +1,215 @@
+/*!
+Root finding in one dimension: iteration methods for the numerical solution of `F(x) = 0`, and tools for analysing
+their convergence.
+
+The `root-finder` binary is a command-line front end to this library; see `cli`.
+
+`no_std`
+--------
+Without the default `std` feature, the crate is `#![no_std]`, for running the solvers on embedded targets. The `alloc`
+feature then provides the solvers and functionals, which record their iterates in a `Vec`, and the `libm` feature the
+floating-point functions that `core` lacks. Reporting, file export, the command-line interface and the test functions
+need `std`, as do the bindings to other languages. For example:
+
+```toml
+CATAM-0-1-ROOT-FINDING-IN-ONE-DIMENSION = { version = "0.1", default-features = false, features = ["alloc", "libm"] }
+```
+*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `tracing` macros below are only used by the solvers, which need `alloc`.
+#![cfg_attr(not(feature = "alloc"), allow(unused_macros))]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!(
+    "without the `std` feature, the `libm` feature is needed for floating-point functions"
+);
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate clap;
+extern crate num;
+use num::Float;
+
+#[cfg(feature = "arbitrary-precision")]
+extern crate astro_float;
+#[cfg(feature = "plot")]
+extern crate plotters;
+// `#![no_std]` declares `core` itself, but with `std` the 2015 edition only resolves `core::` paths if it is declared.
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "python")]
+extern crate pyo3;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+#[cfg(feature = "batch")]
+extern crate toml;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+
+/// Enters an info-level `tracing` span named `$name` until the end of the enclosing block, with the `tracing` feature.
+macro_rules! trace_span {
+    ($name:expr) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::info_span!($name).entered();
+    };
+}
+
+/// Emits a debug-level `tracing` event, as for each iterate of a solver, with the `tracing` feature.
+macro_rules! trace_debug {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        {
+            ::tracing::debug!($($arg)*);
+        }
+    }};
+}
+
+/// Emits an info-level `tracing` event, as for the outcome of a solver, with the `tracing` feature.
+macro_rules! trace_info {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        {
+            ::tracing::info!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(feature = "alloc")]
+pub mod root_search;
+#[cfg(feature = "alloc")]
+pub mod functional;
+#[cfg(feature = "std")]
+pub mod test_function;
+#[cfg(feature = "std")]
+pub mod test_func_derivative;
+#[cfg(feature = "alloc")]
+pub mod analysis;
+pub mod autodiff;
+#[cfg(feature = "batch")]
+pub mod batch;
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "alloc")]
+pub mod chebyshev;
+#[cfg(feature = "std")]
+pub mod cli;
+#[cfg(feature = "alloc")]
+pub mod continuation;
+#[cfg(feature = "std")]
+pub mod export;
+#[cfg(feature = "alloc")]
+pub mod expr;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "interval")]
+pub mod interval;
+#[cfg(feature = "alloc")]
+pub mod optimize;
+#[cfg(feature = "alloc")]
+pub mod polynomial;
+#[cfg(feature = "arbitrary-precision")]
+pub mod precise;
+#[cfg(feature = "alloc")]
+pub mod problem;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub mod test_func_second_derivative;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/**
+A real function of a real variable, as taken by the root-finders.
+
+Solvers take their functions by a generic parameter `F: ObjectiveFn<T> + ?Sized`, so that a closure or `fn` is called
+directly in the solver's loop, without a `Box` or dynamic dispatch. Every `Fn(T) -> T` is an `ObjectiveFn<T>`, including
+the trait object `ContinuousFunction`.
+
+Examples
+--------
+In this example, functions of different types are stored together as `ContinuousFunction`s, and each passed to
+`root_search::fixed_point`.
+```rust
+# use root_finding::*;
+let shift: f64 = 0.5;
+let funcs: Vec<Box<ContinuousFunction>> = vec![Box::new(f64::cos), Box::new(move |x: f64| x.cos() - shift)];
+for func in &funcs {
+    assert!(root_search::fixed_point(func, 1.0, 1e-10, root_search::Budget::new(100, None)).is_ok());
+}
+```
+*/
+pub trait ObjectiveFn<T = f64> {
+    /// The value of the function at `x`.
+    fn eval(&self, x: T) -> T;
+}
+
+impl<T, F: Fn(T) -> T + ?Sized> ObjectiveFn<T> for F {
+    fn eval(&self, x: T) -> T {
+        self(x)
+    }
+}
+
+/**
+A function that can be evaluated at many points in one call, as by the methods that sample it over a grid.
+
+`find_all_roots` and `chebyshev::all_roots` evaluate their function at every point of a grid before searching, and
+`analysis::newton_basins` iterates over every point of a grid at once. They do so through `eval_many`, so a function
+that is cheaper to evaluate in bulk, e.g. with SIMD, on a GPU, in parallel, or by a single call into another language,
+can override it. By default it calls `eval` at each point, and every `Fn(T) -> T` is a `BatchedFunction<T>` with the
+default, so closures are accepted wherever a `BatchedFunction` is.
+
+Examples
+--------
+In this example, a polynomial is evaluated at several points at once by Horner's method, one coefficient at a time
+across all the points, which the compiler can vectorise.
+```rust
+# use root_finding::*;
+struct Cubic;
+impl ObjectiveFn for Cubic {
+    fn eval(&self, x: f64) -> f64 {
+        x * x * x - 2.0 * x - 5.0
+    }
+}
+impl BatchedFunction for Cubic {
+    fn eval_many(&self, xs: &[f64], out: &mut [f64]) {
+        out.iter_mut().for_each(|y| *y = 1.0);
+        for &c in &[0.0, -2.0, -5.0] {
+            out.iter_mut().zip(xs).for_each(|(y, &x)| *y = *y * x + c);
+        }
+    }
+}
+let res = root_search::find_all_roots(&Cubic, (0.0, 4.0), 100, 1e-12);
+assert!((res[0].root - 2.0945514815423265).abs() < 1e-12);
+```
+*/
+pub trait BatchedFunction<T: Copy = f64>: ObjectiveFn<T> {
+    /// Writes the value of the function at each point of `xs` to the same position of `out`, which is as long.
+    fn eval_many(&self, xs: &[T], out: &mut [T]) {
+        for (y, &x) in out.iter_mut().zip(xs) {
+            *y = self.eval(x);
+        }
+    }
+}
+
+impl<T: Copy, F: Fn(T) -> T + ?Sized> BatchedFunction<T> for F {}
+
+/// A real function of a real variable as a trait object, for storing functions of different types together, and as
+/// returned by the functionals.
+pub type ContinuousFunction<'a, T = f64> = dyn Fn(T) -> T + 'a;
+
+/// An owned `ContinuousFunction`, as returned by the functionals.
+#[cfg(feature = "alloc")]
+pub type BoxedFn<'a, T = f64> = alloc::boxed::Box<ContinuousFunction<'a, T>>;
@@ -1,20 +1,12 @@
-#![allow(dead_code)]
-
-extern crate num;
-use num::signum;
-use num::traits::pow;
-
-extern crate composing;
-use composing::compose_fn;
-
-mod root_search;
-mod functional;
-mod test_function;
-mod test_func_derivative;
-
-type ContinuousFunction<'a> = dyn Fn(f64) -> f64 + 'a;
-
-fn main() -> Result<(), Box<&'static str>> {
-
-    Ok(())
-}
\ No newline at end of file
+extern crate clap;
+extern crate root_finding;
+
+use clap::Parser;
+use root_finding::cli;
+
+fn main() {
+    if let Err(err) = cli::run(&cli::Args::parse()) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,207 @@
+/*!
+Locating the local extrema of a function, by the same machinery as the root-finders.
+
+Functions
+---------
+* `find_extremum` : Finds every local extremum in an interval, as a root of the derivative, and classifies each.
+* `golden_section` : Finds a local minimum in an interval by golden-section search, without the derivative.
+
+Types
+-----
+* `Extremum` : A stationary point of a function, and whether it is a minimum or maximum.
+* `ExtremumKind` : Whether a stationary point is a minimum, maximum or neither.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use root_search::{self, CountedFunction, RootError, RootResult, TerminationReason};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use BatchedFunction;
+use Float;
+use ObjectiveFn;
+
+/// Whether a stationary point is a local minimum, a local maximum, or neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExtremumKind {
+    Minimum,
+    Maximum,
+    /// The second difference vanishes, as at a stationary point of inflection.
+    Inflection,
+}
+
+/// A stationary point of a function.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Extremum<T = f64> {
+    /// The location of the stationary point, with the iterates computed on the way to it.
+    pub location: RootResult<T>,
+    /// The value of the function there.
+    pub value: T,
+    pub kind: ExtremumKind,
+}
+
+/// Classifies the stationary point `x` by the sign of the second difference `F(x+h) - 2F(x) + F(x-h)`.
+fn classify<T: Float, F: ObjectiveFn<T> + ?Sized>(func: &F, x: T, value: T) -> ExtremumKind {
+    // The second difference has truncation error O(h^2) and rounding error O(eps/h^2), balanced at h ~ eps^(1/4).
+    let h: T = T::epsilon().sqrt().sqrt() * x.abs().max(T::one());
+    let second_diff: T = func.eval(x + h) - (value + value) + func.eval(x - h);
+    if second_diff > T::zero() {
+        ExtremumKind::Minimum
+    } else if second_diff < T::zero() {
+        ExtremumKind::Maximum
+    } else {
+        ExtremumKind::Inflection
+    }
+}
+
+/**
+Finds every local extremum of a differentiable function in an interval.
+
+The extrema are found as roots of the derivative by `root_search::find_all_roots`, so only those at which the
+derivative changes sign between subdivision points are found, and each is classified by the sign of the second
+difference of `func` there.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `deriv` : The derivative of `func`.
+* `domain` : The start and end points of the search interval.
+* `n_subdivisions` : The number of sub-intervals to scan for sign changes of `deriv`.
+* `trunc_err` : A float representing the acceptable truncation error in the location of each extremum.
+
+Returns
+-------
+* `Vec<Extremum<T>>` : The extrema found, in increasing order of location. Sub-intervals in which the search for a root
+  of `deriv` fails are skipped, as by `root_search::find_all_roots`.
+
+Examples
+--------
+In this example, `optimize::find_extremum` finds the local maximum of `trig` at x = -arccos(2/3) and its local
+minimum at x = arccos(2/3).
+```rust
+# use root_finding::*;
+use root_finding::optimize::ExtremumKind;
+let res = optimize::find_extremum(&test_function::trig, &test_func_derivative::trig, (-2.0, 2.0), 100, 1e-10);
+let kinds: Vec<ExtremumKind> = res.iter().map(|e| e.kind).collect();
+assert_eq!(vec![ExtremumKind::Maximum, ExtremumKind::Minimum], kinds);
+assert!((res[1].location.root - (2.0_f64 / 3.0).acos()).abs() < 1e-10);
+```
+*/
+pub fn find_extremum<T: Float, F: ObjectiveFn<T> + ?Sized, D: BatchedFunction<T> + ?Sized>(
+    func: &F,
+    deriv: &D,
+    domain: (T, T),
+    n_subdivisions: usize,
+    trunc_err: T,
+) -> Vec<Extremum<T>> {
+    trace_span!("find_extremum");
+    root_search::find_all_roots(deriv, domain, n_subdivisions, trunc_err)
+        .into_iter()
+        .map(|location| {
+            let value: T = func.eval(location.root);
+            Extremum {
+                kind: classify(func, location.root, value),
+                location,
+                value,
+            }
+        })
+        .collect()
+}
+
+/**
+Finds a local minimum of a continuous function in an interval by golden-section search.
+
+The interval is narrowed by comparing the function at two interior points dividing it in the golden ratio, so that one
+of them can be reused at the next step, and only one new evaluation is needed per step. The interval shrinks by a factor
+of `1/φ ≈ 0.618` per step, so the method converges linearly, like binary search. For a maximum, minimise `-func`.
+
+Parameters
+----------
+* `func` : A continuous function, unimodal over `domain` for the minimum found to be its global minimum there.
+* `domain` : The start and end points of the search interval.
+* `trunc_err` : A float representing the acceptable truncation error in the location of the minimum.
+
+Returns
+-------
+* `Extremum<T>` : The minimum, with the midpoints of the intervals searched as its history.
+
+Errors
+------
+* `RootError<T>` : If `func` is NaN or infinite at an interior point, with reason `TerminationReason::NonFinite`.
+
+Examples
+--------
+In this example, `optimize::golden_section` finds the minimum of `trig` at x = arccos(2/3).
+```rust
+# use root_finding::*;
+let res = optimize::golden_section(&test_function::trig, (0.0, 2.0), 1e-8)?;
+assert!((res.location.root - (2.0_f64 / 3.0).acos()).abs() < 1e-7);
+assert_eq!(optimize::ExtremumKind::Minimum, res.kind);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn golden_section<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    domain: (T, T),
+    trunc_err: T,
+) -> Result<Extremum<T>, RootError<T>> {
+    trace_span!("golden_section");
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let inv_phi: T = (T::from(5.0).unwrap().sqrt() - T::one()) / T::from(2.0).unwrap();
+    let (mut lo, mut hi): (T, T) = (domain.0.min(domain.1), domain.0.max(domain.1));
+    let mut history: Vec<T> = Vec::new();
+
+    let mut left: T = hi - inv_phi * (hi - lo);
+    let mut right: T = lo + inv_phi * (hi - lo);
+    let (mut f_left, mut f_right): (T, T) = (func.call(left), func.call(right));
+    loop {
+        for &(x, val) in &[(left, f_left), (right, f_right)] {
+            if !val.is_finite() {
+                history.push(x);
+                return Err(RootError {
+                    reason: TerminationReason::NonFinite,
+                    history,
+                    residuals: Vec::new(),
+                    evaluations: func.evaluations(),
+                });
+            }
+        }
+        if hi - lo < trunc_err {
+            break;
+        }
+        history.push(lo + (hi - lo) / T::from(2.0).unwrap());
+        trace_debug!(
+            iteration = history.len(),
+            lo = lo.to_f64(),
+            hi = hi.to_f64()
+        );
+        if f_left < f_right {
+            hi = right;
+            right = left;
+            f_right = f_left;
+            left = hi - inv_phi * (hi - lo);
+            f_left = func.call(left);
+        } else {
+            lo = left;
+            left = right;
+            f_left = f_right;
+            right = lo + inv_phi * (hi - lo);
+            f_right = func.call(right);
+        }
+    }
+
+    let root: T = lo + (hi - lo) / T::from(2.0).unwrap();
+    let value: T = func.call(root);
+    Ok(Extremum {
+        location: RootResult {
+            root,
+            history,
+            residuals: Vec::new(),
+            evaluations: func.evaluations(),
+        },
+        value,
+        kind: ExtremumKind::Minimum,
+    })
+}
@@ -0,0 +1,423 @@
+/*!
+Polynomials with real coefficients, with exact evaluation and differentiation.
+
+Types
+-----
+* `Polynomial` : A polynomial, stored as a vector of coefficients.
+
+Functions
+---------
+* `all_real_roots` : Finds every real root of a polynomial, by Newton-Raphson iteration and deflation.
+* `durand_kerner` : Finds every complex root of a polynomial at once, by simultaneous iteration.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul};
+use functional;
+use num::{Complex, Zero};
+use root_search::{self, Budget};
+use BatchedFunction;
+use Float;
+use ObjectiveFn;
+
+/// The maximum number of Newton-Raphson iterations spent looking for each root.
+const MAX_ITER: usize = 1000;
+
+/**
+A polynomial `a_0 + a_1 x + ... + a_n x^n`, stored as the coefficients `[a_0, a_1, ..., a_n]`.
+
+A polynomial is an `ObjectiveFn` and a `BatchedFunction` of real and complex numbers, evaluating many points at once
+by Horner's method across all of them, so can be passed directly to the solvers.
+
+Examples
+--------
+In this example, we build the cubic `test_function::polynom` and its derivative.
+```rust
+# use root_finding::*;
+let poly = polynomial::Polynomial::new(vec![-8.0, 20.0, -8.5, 1.0]);
+assert_eq!(0.0, poly.eval(4.0));
+assert_eq!("3x^2 - 17x + 20", poly.derivative().to_string());
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coeffs: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, in increasing order of degree. Trailing zeros are discarded.
+    pub fn new(mut coeffs: Vec<f64>) -> Polynomial {
+        while coeffs.last() == Some(&0.0) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    /// The monic polynomial `(x - r_1)(x - r_2)...(x - r_n)` with the given roots.
+    pub fn from_roots(roots: &[f64]) -> Polynomial {
+        let mut coeffs: Vec<f64> = vec![1.0];
+        for &root in roots {
+            // Multiply by (x - root): shift every coefficient up one degree, and subtract root times the original.
+            coeffs.insert(0, 0.0);
+            for i in 0..coeffs.len() - 1 {
+                coeffs[i] -= root * coeffs[i + 1];
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+
+    /// The coefficients, in increasing order of degree. The zero polynomial has no coefficients.
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// The degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method, in any floating-point type.
+    pub fn eval<T: Float>(&self, x: T) -> T {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, &c| acc * x + T::from(c).unwrap())
+    }
+
+    /// Evaluates the polynomial at a complex number `z` using Horner's method.
+    pub fn eval_complex(&self, z: Complex<f64>) -> Complex<f64> {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Complex::new(0.0, 0.0), |acc, &c| acc * z + c)
+    }
+
+    /// The derivative of the polynomial.
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, &c)| power as f64 * c)
+                .collect(),
+        )
+    }
+
+    /**
+    Divides the polynomial by `x - root` using synthetic division, discarding the remainder.
+
+    When `root` is a root of the polynomial the remainder is zero (up to rounding), and the quotient is the polynomial
+    with that root removed.
+    */
+    pub fn deflate(&self, root: f64) -> Polynomial {
+        let mut quotient: Vec<f64> = vec![0.0; self.coeffs.len().saturating_sub(1)];
+        let mut carry: f64 = 0.0;
+        for (i, &c) in self.coeffs.iter().enumerate().skip(1).rev() {
+            carry = carry * root + c;
+            quotient[i - 1] = carry;
+        }
+        Polynomial::new(quotient)
+    }
+
+    /// Divides the polynomial by `(x - root)(x - conj(root))`, discarding the remainder, to remove a complex
+    /// conjugate pair of roots while keeping the coefficients real.
+    fn deflate_pair(&self, root: Complex<f64>) -> Polynomial {
+        // The quadratic factor is `x^2 + b x + c`.
+        let (b, c): (f64, f64) = (-2.0 * root.re, root.norm_sqr());
+        let n: usize = self.coeffs.len();
+        if n < 3 {
+            return Polynomial::new(Vec::new());
+        }
+        let mut quotient: Vec<f64> = vec![0.0; n - 2];
+        for k in (2..n).rev() {
+            let above: f64 = quotient.get(k - 1).copied().unwrap_or(0.0);
+            let above2: f64 = quotient.get(k).copied().unwrap_or(0.0);
+            quotient[k - 2] = self.coeffs[k] - b * above - c * above2;
+        }
+        Polynomial::new(quotient)
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at every point of `xs` by Horner's method, a coefficient at a
+/// time across all the points, so that the inner loop is a simple multiply-add over the slices.
+fn horner_many<T: Copy + Zero + Mul<Output = T> + Add<f64, Output = T>>(
+    coeffs: &[f64],
+    xs: &[T],
+    out: &mut [T],
+) {
+    for y in out.iter_mut() {
+        *y = T::zero();
+    }
+    for &c in coeffs.iter().rev() {
+        for (y, &x) in out.iter_mut().zip(xs) {
+            *y = *y * x + c;
+        }
+    }
+}
+
+impl ObjectiveFn for Polynomial {
+    fn eval(&self, x: f64) -> f64 {
+        Polynomial::eval(self, x)
+    }
+}
+
+impl BatchedFunction for Polynomial {
+    fn eval_many(&self, xs: &[f64], out: &mut [f64]) {
+        horner_many(&self.coeffs, xs, out);
+    }
+}
+
+impl ObjectiveFn<Complex<f64>> for Polynomial {
+    fn eval(&self, z: Complex<f64>) -> Complex<f64> {
+        self.eval_complex(z)
+    }
+}
+
+impl BatchedFunction<Complex<f64>> for Polynomial {
+    fn eval_many(&self, zs: &[Complex<f64>], out: &mut [Complex<f64>]) {
+        horner_many(&self.coeffs, zs, out);
+    }
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coeffs.is_empty() {
+            return write!(f, "0");
+        }
+        let mut first: bool = true;
+        for (power, &c) in self.coeffs.iter().enumerate().rev() {
+            if c == 0.0 {
+                continue;
+            }
+            if first {
+                if c < 0.0 {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {} ", if c < 0.0 { "-" } else { "+" })?;
+            }
+            first = false;
+
+            let magnitude: f64 = c.abs();
+            if magnitude != 1.0 || power == 0 {
+                write!(f, "{}", magnitude)?;
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The fractions of a Laguerre step taken every `LAGUERRE_CYCLE` iterations, to break the rare limit cycles.
+const LAGUERRE_FRACTIONS: [f64; 8] = [0.5, 0.25, 0.75, 0.13, 0.38, 0.62, 0.88, 1.0];
+const LAGUERRE_CYCLE: usize = 10;
+
+/**
+Finds a root of a polynomial of degree at least 1 by Laguerre's method in the complex plane, starting from 0.
+
+With `n` the degree, `G = p'/p` and `H = G^2 - p''/p`, each step is `z -> z - n/(G ± sqrt((n-1)(nH - G^2)))`, the
+sign chosen to make the denominator larger. Laguerre's method converges to some root from any starting point for
+almost every polynomial, cubically at a simple root; the occasional limit cycle is broken by taking a fraction of the
+step every `LAGUERRE_CYCLE` iterations, as in Numerical Recipes.
+
+Returns `None` if the iteration fails to converge in `MAX_ITER` iterations.
+*/
+fn laguerre(poly: &Polynomial, trunc_err: f64) -> Option<Complex<f64>> {
+    let n: f64 = poly.degree()? as f64;
+    let (deriv, second): (Polynomial, Polynomial) =
+        (poly.derivative(), poly.derivative().derivative());
+    let mut z: Complex<f64> = Complex::new(0.0, 0.0);
+    for iteration in 1..=MAX_ITER {
+        let p: Complex<f64> = poly.eval_complex(z);
+        if p.norm() == 0.0 {
+            return Some(z);
+        }
+        let g: Complex<f64> = deriv.eval_complex(z) / p;
+        let h: Complex<f64> = g * g - second.eval_complex(z) / p;
+        let sqrt: Complex<f64> = ((n - 1.0) * (n * h - g * g)).sqrt();
+        let (plus, minus): (Complex<f64>, Complex<f64>) = (g + sqrt, g - sqrt);
+        let denom: Complex<f64> = if plus.norm() >= minus.norm() {
+            plus
+        } else {
+            minus
+        };
+        let step: Complex<f64> = if denom.norm() > 0.0 {
+            n / denom
+        } else {
+            // At a stationary point of `p`, any step away will do.
+            Complex::from_polar(1.0 + z.norm(), iteration as f64)
+        };
+        if !step.re.is_finite() || !step.im.is_finite() {
+            return None;
+        }
+        trace_debug!(iteration, re = z.re, im = z.im, step = step.norm());
+        if step.norm() < trunc_err {
+            return Some(z - step);
+        }
+        z -= if iteration % LAGUERRE_CYCLE == 0 {
+            step * LAGUERRE_FRACTIONS[(iteration / LAGUERRE_CYCLE) % LAGUERRE_FRACTIONS.len()]
+        } else {
+            step
+        };
+    }
+    None
+}
+
+/**
+Finds every real root of a polynomial.
+
+Roots are found one at a time by Laguerre's method on the deflated polynomial, starting from `x=0` so that the
+smallest roots are removed first (which keeps the deflation numerically stable). Unlike Newton-Raphson, which can
+fall into a cycle and so miss a real root, as from 0 on `x^3 - 2x + 2`, Laguerre's method converges to a root from
+any starting point. The iteration runs in the complex plane: a real root is removed by dividing by `x - r`, and a
+complex one with its conjugate, by dividing by the real quadratic factor, until no roots remain. Each real root is
+then polished by Newton-Raphson iteration on the original polynomial, to remove the error accumulated through
+deflation. A root is taken to be real if its imaginary part is smaller than `sqrt(trunc_err)`, relative to its size,
+since a repeated real root is only located to about the square root of the precision, and may acquire a small
+imaginary part. A root which comes out NaN or infinite, e.g. because the leading coefficient underflows, is deflated
+but left out.
+
+Parameters
+----------
+* `poly` : A polynomial.
+* `trunc_err` : A float representing the acceptable truncation error for each root.
+
+Returns
+-------
+* `Vec<f64>` : The finite real roots of `poly` in increasing order, repeated according to multiplicity.
+
+Examples
+--------
+In this example, `polynomial::all_real_roots` should return the simple root 0.5 and the double root 4.
+```rust
+# use root_finding::*;
+let roots: Vec<f64> = polynomial::all_real_roots(&test_function::POLYNOM, 1e-10);
+let rounded: Vec<f64> = roots.iter().map(|x| (x*10.0).round()/10.0).collect();
+assert_eq!(vec![0.5, 4.0, 4.0], rounded);
+```
+*/
+pub fn all_real_roots(poly: &Polynomial, trunc_err: f64) -> Vec<f64> {
+    trace_span!("all_real_roots");
+    let original = |x: f64| -> f64 { poly.eval(x) };
+    let original_deriv: Polynomial = poly.derivative();
+    let original_deriv = |x: f64| -> f64 { original_deriv.eval(x) };
+
+    let mut roots: Vec<f64> = Vec::new();
+    let mut remaining: Polynomial = poly.clone();
+    while let Some(degree) = remaining.degree() {
+        let estimate: f64 = match degree {
+            0 => break,
+            1 => -remaining.coeffs[0] / remaining.coeffs[1],
+            _ => match laguerre(&remaining, trunc_err) {
+                Some(root) if root.im.abs() < trunc_err.sqrt() * root.norm().max(1.0) => root.re,
+                Some(root) => {
+                    trace_debug!(re = root.re, im = root.im, degree, "deflating complex pair");
+                    remaining = remaining.deflate_pair(root);
+                    continue;
+                }
+                None => break,
+            },
+        };
+
+        let polish = functional::x_minus(functional::newton_raphson(original, original_deriv));
+        let root: f64 = match root_search::fixed_point(
+            &polish,
+            estimate,
+            trunc_err,
+            Budget::new(MAX_ITER, None),
+        ) {
+            Ok(res) => res.root,
+            Err(_) => estimate,
+        };
+
+        trace_debug!(root, degree, "deflating");
+        if root.is_finite() {
+            roots.push(root);
+        }
+        remaining = remaining.deflate(estimate);
+    }
+
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
+
+/**
+Finds every complex root of a polynomial simultaneously, using the Durand-Kerner (Weierstrass) method.
+
+Writing the monic polynomial as `p(z) = (z-r_1)...(z-r_n)`, each approximation is updated by
+`z_i -> z_i - p(z_i)/prod_{j != i}(z_i - z_j)`, all at once. Unlike the other methods in this crate this needs no
+initial guess or bracket: the iteration starts from points spread around a circle containing every root. Convergence
+is quadratic for simple roots, and linear for repeated roots.
+
+Parameters
+----------
+* `poly` : A polynomial of degree at least 1.
+* `trunc_err` : A float representing the acceptable truncation error for each root.
+* `max_iter` : The maximum number of iterations the algorithm will use before it declares the roots not found.
+
+Returns
+-------
+* `Vec<Complex<f64>>` : The roots of `poly`, repeated according to multiplicity.
+
+Errors
+------
+* `Vec<Complex<f64>>` : If the iteration fails to converge in `max_iter`, returns the current approximations as an error.
+
+Examples
+--------
+In this example, `polynomial::durand_kerner` should return the roots ±i of `x^2 + 1`.
+```rust
+# use root_finding::*;
+let roots = polynomial::durand_kerner(&polynomial::Polynomial::new(vec![1.0, 0.0, 1.0]), 1e-10, 100)?;
+let mut imag: Vec<f64> = roots.iter().map(|z| (z.im*10.0).round()/10.0).collect();
+imag.sort_by(|a, b| a.partial_cmp(b).unwrap());
+assert_eq!(vec![-1.0, 1.0], imag);
+# Ok::<(), Vec<num::Complex<f64>>>(())
+```
+*/
+pub fn durand_kerner(
+    poly: &Polynomial,
+    trunc_err: f64,
+    max_iter: usize,
+) -> Result<Vec<Complex<f64>>, Vec<Complex<f64>>> {
+    trace_span!("durand_kerner");
+    let degree: usize = match poly.degree() {
+        Some(degree) => degree,
+        None => return Err(Vec::new()),
+    };
+    let leading: f64 = poly.coeffs[degree];
+    let monic: Polynomial = Polynomial::new(poly.coeffs.iter().map(|c| c / leading).collect());
+
+    // Every root lies within the Cauchy bound 1 + max|a_i/a_n|. The starting points are powers of a complex number
+    // which is neither real nor a root of unity, so that no two coincide and none are symmetric under conjugation.
+    let radius: f64 = 1.0
+        + monic.coeffs[..degree]
+            .iter()
+            .fold(0.0, |acc: f64, c| acc.max(c.abs()));
+    let seed: Complex<f64> = Complex::new(0.4, 0.9);
+    let mut roots: Vec<Complex<f64>> = (0..degree).map(|i| seed.powu(i as u32) * radius).collect();
+
+    for _ in 0..max_iter {
+        let mut max_step: f64 = 0.0;
+        for i in 0..degree {
+            let denom: Complex<f64> = (0..degree)
+                .filter(|&j| j != i)
+                .fold(Complex::new(1.0, 0.0), |acc, j| acc * (roots[i] - roots[j]));
+            let step: Complex<f64> = monic.eval_complex(roots[i]) / denom;
+            roots[i] -= step;
+            max_step = max_step.max(step.norm());
+        }
+        trace_debug!(max_step);
+        if max_step < trunc_err {
+            return Ok(roots);
+        }
+    }
+    Err(roots)
+}
@@ -0,0 +1,231 @@
+/*!
+Arbitrary-precision counterparts of the root-finding algorithms, built on `astro_float::BigFloat`.
+
+The `f64` algorithms in `root_search` cannot resolve a root beyond about `1e-15`, which hides the point at which
+rounding error overtakes truncation error. These versions carry every computation out to a working precision `p`,
+given in bits (so `p=192` is a little over 57 decimal digits).
+
+Only available with the `arbitrary-precision` feature.
+
+Functions
+---------
+* `binary` : Binary search, a.k.a interval bisection.
+* `fixed_point` : Fixed point iteration.
+* `newton` : Newton-Raphson iteration.
+* `trig`, `trig_derivative` : The `trig` test function and its derivative.
+*/
+
+use astro_float::{BigFloat, Consts, RoundingMode};
+use std::cell::RefCell;
+
+/// The rounding mode used throughout this module.
+const RM: RoundingMode = RoundingMode::ToEven;
+
+/// A function of an arbitrary-precision float.
+pub type PreciseFunction<'a> = dyn Fn(&BigFloat) -> BigFloat + 'a;
+
+thread_local! {
+    // Transcendental functions need a cache of constants (pi, e, ...), which is expensive to rebuild per call.
+    static CONSTS: RefCell<Consts> = RefCell::new(Consts::new().expect("Failed to allocate constants cache"));
+}
+
+/**
+Find a root of a continuous function using binary search, to arbitrary precision.
+
+Parameters
+----------
+* `func` : A continuous function with a sign change over the given domain.
+* `domain` : The start and end points of the search interval.
+* `trunc_err` : The acceptable truncation error for the search.
+* `p` : The working precision, in bits.
+
+Returns
+-------
+* `BigFloat` : The root of the function.
+
+Errors
+------
+* If the function doesn't change sign at the endpoints
+
+Examples
+--------
+In this example, `precise::binary` should return -2.883236872558 ± 1e-12.
+```rust
+# extern crate astro_float;
+# extern crate root_finding;
+# use astro_float::{BigFloat, Consts, Radix, RoundingMode};
+# use root_finding::*;
+let p: usize = 128;
+let trunc_err = BigFloat::from_f64(1e-12, p);
+let domain = (BigFloat::from_f64(-3.0, p), BigFloat::from_f64(-2.0, p));
+let res = precise::binary(&|x: &BigFloat| precise::trig(x, p), domain, &trunc_err, p)?;
+assert!(res.sub(&BigFloat::from_f64(-2.883236872558, p), p, RoundingMode::ToEven).abs() < trunc_err);
+# Ok::<(), &'static str>(())
+```
+*/
+pub fn binary(
+    func: &PreciseFunction,
+    domain: (BigFloat, BigFloat),
+    trunc_err: &BigFloat,
+    p: usize,
+) -> Result<BigFloat, &'static str> {
+    trace_span!("precise::binary");
+    let two: BigFloat = BigFloat::from_u8(2, p);
+    let (mut start, mut end): (BigFloat, BigFloat) = domain;
+    let (mut start_val, end_val): (BigFloat, BigFloat) = (func(&start), func(&end));
+
+    if start_val.is_zero() {
+        return Ok(start);
+    }
+    if end_val.is_zero() {
+        return Ok(end);
+    }
+    // Since we only care about signs, we work with the signs of the function values from here on.
+    start_val = start_val.signum();
+    if start_val == end_val.signum() {
+        return Err("Error: no sign change at endpoints!");
+    }
+
+    let val: BigFloat = loop {
+        let midpoint: BigFloat = end.add(&start, p, RM).div(&two, p, RM);
+        let test_val: BigFloat = func(&midpoint);
+        trace_debug!(x = %midpoint);
+
+        if test_val.is_zero() || end.sub(&start, p, RM).abs() < *trunc_err {
+            break midpoint;
+        }
+        let test_val: BigFloat = test_val.signum();
+        if test_val == start_val {
+            start = midpoint;
+            start_val = test_val;
+        } else {
+            end = midpoint;
+        }
+    };
+
+    Ok(val)
+}
+
+/**
+Return the fixed point of a function where one exists, to arbitrary precision.
+
+Parameters
+----------
+* `func` : A continuous function with a fixed point, e.g. a contraction mapping.
+* `initial_val` : An initial guess for the location of the fixed point.
+* `trunc_err` : The acceptable truncation error for the search.
+* `max_iter` : The maximum number of iterations the algorithm will use before it declares there is no fixed point.
+* `p` : The working precision, in bits.
+
+Returns
+-------
+* `BigFloat` : The fixed point.
+* `Vec<BigFloat>` : A vector of all computed iterations.
+
+Errors
+------
+* `Vec<BigFloat>` : If function fails to converge in `max_iter`, returns the current sequence of computed iterations as an error.
+*/
+pub fn fixed_point(
+    func: &PreciseFunction,
+    initial_val: BigFloat,
+    trunc_err: &BigFloat,
+    max_iter: usize,
+    p: usize,
+) -> Result<(BigFloat, Vec<BigFloat>), Vec<BigFloat>> {
+    trace_span!("precise::fixed_point");
+    let mut func_vals: Vec<BigFloat> = Vec::with_capacity(max_iter);
+    let mut current_val: BigFloat = initial_val;
+    for _ in 0..max_iter {
+        func_vals.push(current_val.clone());
+        let next_val: BigFloat = func(&current_val);
+        trace_debug!(iteration = func_vals.len(), x = %current_val);
+        if next_val.sub(&current_val, p, RM).abs() < *trunc_err {
+            return Ok((next_val, func_vals));
+        }
+        current_val = next_val;
+    }
+    func_vals.push(current_val);
+    Err(func_vals)
+}
+
+/**
+Find a root of a differentiable function using Newton-Raphson iteration, to arbitrary precision.
+
+This is fixed point iteration on `x - F(x)/F'(x)`. As in `functional::newton_raphson`, a step of `1` is taken wherever
+the derivative vanishes.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `deriv` : The derivative of `func`.
+* `initial_val` : An initial guess for the location of the root.
+* `trunc_err` : The acceptable truncation error for the search.
+* `max_iter` : The maximum number of iterations the algorithm will use before it declares there is no root.
+* `p` : The working precision, in bits.
+
+Returns
+-------
+* `BigFloat` : The root.
+* `Vec<BigFloat>` : A vector of all computed iterations.
+
+Errors
+------
+* `Vec<BigFloat>` : If function fails to converge in `max_iter`, returns the current sequence of computed iterations as an error.
+
+Examples
+--------
+In this example, `precise::newton` finds the root of `trig` to 50 decimal places.
+```rust
+# extern crate astro_float;
+# extern crate root_finding;
+# use astro_float::{BigFloat, Consts, Radix, RoundingMode};
+# use root_finding::*;
+let p: usize = 192;
+let trunc_err = BigFloat::parse("1e-50", Radix::Dec, p, RoundingMode::ToEven, &mut Consts::new().unwrap());
+let (res, _seq) = precise::newton(
+    &|x: &BigFloat| precise::trig(x, p),
+    &|x: &BigFloat| precise::trig_derivative(x, p),
+    BigFloat::from_f64(-4.0, p),
+    &trunc_err,
+    20,
+    p,
+)?;
+println!("Root is at {}", res);
+# Ok::<(), Vec<BigFloat>>(())
+```
+*/
+pub fn newton(
+    func: &PreciseFunction,
+    deriv: &PreciseFunction,
+    initial_val: BigFloat,
+    trunc_err: &BigFloat,
+    max_iter: usize,
+    p: usize,
+) -> Result<(BigFloat, Vec<BigFloat>), Vec<BigFloat>> {
+    trace_span!("precise::newton");
+    let step = |x: &BigFloat| -> BigFloat {
+        let deriv_val: BigFloat = deriv(x);
+        if deriv_val.is_zero() {
+            x.sub(&BigFloat::from_u8(1, p), p, RM)
+        } else {
+            x.sub(&func(x).div(&deriv_val, p, RM), p, RM)
+        }
+    };
+    fixed_point(&step, initial_val, trunc_err, max_iter, p)
+}
+
+/// The `trig` test function, `2x - 3sin(x) + 5`, at working precision `p`.
+pub fn trig(x: &BigFloat, p: usize) -> BigFloat {
+    let sin_x: BigFloat = CONSTS.with(|cc| x.sin(p, RM, &mut cc.borrow_mut()));
+    BigFloat::from_u8(2, p)
+        .mul(x, p, RM)
+        .sub(&BigFloat::from_u8(3, p).mul(&sin_x, p, RM), p, RM)
+        .add(&BigFloat::from_u8(5, p), p, RM)
+}
+
+/// The derivative of the `trig` test function, `2 - 3cos(x)`, at working precision `p`.
+pub fn trig_derivative(x: &BigFloat, p: usize) -> BigFloat {
+    let cos_x: BigFloat = CONSTS.with(|cc| x.cos(p, RM, &mut cc.borrow_mut()));
+    BigFloat::from_u8(2, p).sub(&BigFloat::from_u8(3, p).mul(&cos_x, p, RM), p, RM)
+}
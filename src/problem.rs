@@ -0,0 +1,273 @@
+/*!
+Problems with named parameters, such as the `k` of `functional::frac` or the coefficients of `trig`, which can be
+changed in place between searches.
+
+A family of functions `F(x; k)` is otherwise searched by building a new closure for each value of `k`, capturing it by
+reference or by value, which ties the closure's lifetime to that of `k` and makes it awkward to store or pass around.
+A `Problem` instead owns its parameters, and passes them to its function at each evaluation, so a single problem can be
+built once, handed to the solvers as an `ObjectiveFn`, and re-run as its parameters are changed.
+
+Types
+-----
+* `Params` : Named parameter values.
+* `Problem` : A function of `x` and named parameters, with the current values of the parameters.
+
+Functions
+---------
+* `frac` : The fixed-point map `x - F(x)/(2+k)`, with the parameter `k`.
+*/
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use core::ops::Index;
+use BatchedFunction;
+use Float;
+use ObjectiveFn;
+
+/// The error returned on setting or sweeping a parameter a problem doesn't have.
+const NO_SUCH_PARAMETER: &str = "Error: no such parameter!";
+
+/// Named parameter values, in the order they were added.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Params<T = f64> {
+    values: Vec<(String, T)>,
+}
+
+impl<T: Copy> Params<T> {
+    /// The value of the parameter `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<T> {
+        self.values
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Every parameter, as `(name, value)`, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, T)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// A mutable reference to the value of the parameter `name`, if there is one.
+    fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        self.values
+            .iter_mut()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Looks up a parameter by name, as `params["k"]`, panicking if there is no such parameter.
+impl<T> Index<&str> for Params<T> {
+    type Output = T;
+
+    fn index(&self, name: &str) -> &T {
+        self.values
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| panic!("no parameter named {:?}", name))
+    }
+}
+
+/// Formats the parameters as e.g. `a = 2, b = 3`.
+impl<T: fmt::Display> fmt::Display for Params<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (n, (name, value)) in self.values.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A function of a point and the parameters, as evaluated by a `Problem`.
+type ParamFn<'a, T> = dyn Fn(T, &Params<T>) -> T + 'a;
+
+/**
+A function `F(x; p)` of `x` and named parameters `p`, together with the current values of the parameters.
+
+A problem is an `ObjectiveFn` and a `BatchedFunction`, evaluating its function with the current parameters, so it can
+be passed directly to the solvers. Its parameters are declared with `with_param`, and changed with `set_param`, or run
+through a list of values with `sweep`.
+
+Examples
+--------
+In this example, `trig` is written with its coefficients as parameters, and its root found for two values of the
+constant term.
+```rust
+# use root_finding::*;
+use root_finding::problem::Problem;
+use root_finding::root_search::{Bracket, Budget};
+let mut trig = Problem::new(|x: f64, p| p["a"] * x - p["b"] * x.sin() + p["c"])
+    .with_param("a", 2.0)
+    .with_param("b", 3.0)
+    .with_param("c", 5.0);
+let bracket = Bracket::new(&trig, -4.0, 0.0)?;
+let res = root_search::chandrupatla(&trig, bracket, 1e-12, Budget::unlimited())?;
+assert!((res.root + 2.8832368725582835).abs() < 1e-12);
+
+trig.set_param("c", 0.0)?;
+let bracket = Bracket::new(&trig, -1.0, 1.0)?;
+let res = root_search::chandrupatla(&trig, bracket, 1e-12, Budget::unlimited())?;
+assert!(res.root.abs() < 1e-12);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub struct Problem<'a, T = f64> {
+    func: Box<ParamFn<'a, T>>,
+    params: Params<T>,
+}
+
+impl<'a, T: Copy> Problem<'a, T> {
+    /// Creates a problem from a function of `x` and the parameters, with no parameters yet.
+    pub fn new<F: Fn(T, &Params<T>) -> T + 'a>(func: F) -> Problem<'a, T> {
+        Problem {
+            func: Box::new(func),
+            params: Params { values: Vec::new() },
+        }
+    }
+
+    /// Declares the parameter `name`, with its initial value, or sets its value if it is already declared.
+    pub fn with_param(mut self, name: &str, value: T) -> Problem<'a, T> {
+        match self.params.get_mut(name) {
+            Some(param) => *param = value,
+            None => self.params.values.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    /// The current value of the parameter `name`, if there is one.
+    pub fn param(&self, name: &str) -> Option<T> {
+        self.params.get(name)
+    }
+
+    /// The current values of every parameter.
+    pub fn params(&self) -> &Params<T> {
+        &self.params
+    }
+
+    /**
+    Changes the value of the parameter `name`.
+
+    Errors
+    ------
+    * If the problem has no parameter `name`, as declared by `with_param`.
+    */
+    pub fn set_param(&mut self, name: &str, value: T) -> Result<(), &'static str> {
+        *self.params.get_mut(name).ok_or(NO_SUCH_PARAMETER)? = value;
+        Ok(())
+    }
+
+    /**
+    Runs `solve` on the problem with the parameter `name` set to each of `values` in turn, then restores its value.
+
+    Parameters
+    ----------
+    * `name` : The parameter to vary.
+    * `values` : The values to give it.
+    * `solve` : What to do with the problem for each value, e.g. a root search.
+
+    Returns
+    -------
+    * `Vec<R>` : The result of `solve` for each of `values`, in order.
+
+    Errors
+    ------
+    * If the problem has no parameter `name`, as declared by `with_param`.
+
+    Examples
+    --------
+    In this example, fixed-point iteration of `x - trig(x)/(2+k)` is run for several values of `k`, converging in a
+    few iterations for `k` near 2.9, where the map's derivative at the root is near zero, and slowly for `k = 16`.
+    ```rust
+    # use root_finding::*;
+    use root_finding::root_search::{self, Budget};
+    let mut g = problem::frac(test_function::trig, 0.0);
+    let iterations: Vec<usize> = g.sweep("k", &[2.85, 2.95, 16.0], |g| {
+        root_search::fixed_point(g, -2.0, 1e-10, Budget::new(100, None)).map_or(100, |res| res.history.len())
+    })?;
+    assert!(iterations[0] < 10 && iterations[1] < 10);
+    assert!(iterations[2] > 30);
+    assert_eq!(Some(0.0), g.param("k"));
+    # Ok::<(), Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn sweep<R, S: FnMut(&Problem<'a, T>) -> R>(
+        &mut self,
+        name: &str,
+        values: &[T],
+        mut solve: S,
+    ) -> Result<Vec<R>, &'static str> {
+        let original: T = self.param(name).ok_or(NO_SUCH_PARAMETER)?;
+        let mut results: Vec<R> = Vec::with_capacity(values.len());
+        for &value in values {
+            self.set_param(name, value)?;
+            results.push(solve(self));
+        }
+        self.set_param(name, original)?;
+        Ok(results)
+    }
+}
+
+impl<'a, T> ObjectiveFn<T> for Problem<'a, T> {
+    fn eval(&self, x: T) -> T {
+        (self.func)(x, &self.params)
+    }
+}
+
+impl<'a, T: Copy> BatchedFunction<T> for Problem<'a, T> {}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Problem<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Problem")
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+/**
+The fixed-point map `x - F(x)/(2+k)` of `functional::frac`, as a problem with the parameter `k`.
+
+Unlike `functional::frac`, which borrows `k` for as long as the map is used, the problem owns `k`, so it can be changed
+with `Problem::set_param` or `Problem::sweep` without building a new map.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `k` : The initial value of the parameter `k`.
+
+Returns
+-------
+* `Problem` : The map `x - F(x)/(2+k)`, or `x - 1` where `k = -2`, as `functional::frac`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+let g = problem::frac(test_function::trig, 2.85);
+let res = root_search::fixed_point(&g, -2.0, 1e-10, root_search::Budget::new(20, None))?;
+assert!((res.root + 2.8832368726).abs() < 1e-9);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn frac<'a, T: Float + 'a, F: ObjectiveFn<T> + 'a>(func: F, k: T) -> Problem<'a, T> {
+    Problem::new(move |x: T, params: &Params<T>| -> T {
+        let two: T = T::one() + T::one();
+        let k: T = params["k"];
+        if k == -two {
+            x - T::one()
+        } else {
+            x - func.eval(x) / (two + k)
+        }
+    })
+    .with_param("k", k)
+}
@@ -0,0 +1,280 @@
+/*!
+Python bindings, built as the `root_finding` extension module with the `python` feature.
+
+Python callables are accepted wherever the solvers take a function, and the results are returned as Python objects whose
+attributes are plain floats and lists, ready for pandas or matplotlib. Build the module with
+[maturin](https://www.maturin.rs/), e.g. `maturin develop`, using the settings in `pyproject.toml`; maturin asks cargo
+for the `cdylib` that Python loads, so the crate itself is only built as a Rust library.
+
+```python
+import math
+import pandas as pd
+import root_finding
+
+res = root_finding.newton(lambda x: 2*x - 3*math.sin(x) + 5, lambda x: 2 - 3*math.cos(x), -4.0, 1e-12)
+report = root_finding.estimate_order(res.history, res.root)
+df = pd.DataFrame({"x": res.history, "error": report.errors, "order": report.orders})
+```
+
+Functions
+---------
+* `binary` : Binary search, returning the final bracket `(lo, hi)` alongside the result.
+* `fixed_point` : Fixed point iteration.
+* `newton` : Newton-Raphson iteration, with the derivative given as a second callable.
+* `estimate_order` : Estimates the order of convergence of a sequence of iterates.
+* `table` : Formats a sequence of iterates as a Markdown or LaTeX table.
+
+Types
+-----
+* `RootResult` : A root, with its iterates and number of evaluations.
+* `ConvergenceReport` : The errors, ratios and order estimates of a sequence of iterates.
+* `RootFindingError` : The exception raised when a search fails.
+*/
+
+use analysis;
+use functional;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use report::{self, TableFormat, TableOptions};
+use root_search::{self, Bracket, Budget, RootError};
+use std::cell::RefCell;
+use std::time::Duration;
+use BoxedFn;
+use ContinuousFunction;
+
+create_exception!(
+    root_finding,
+    RootFindingError,
+    PyException,
+    "Raised when a root search fails to converge, or the function is not finite."
+);
+
+/**
+A Python callable, evaluated as a `ContinuousFunction`.
+
+Solvers can't propagate exceptions, so if the callable raises, or returns something other than a float, the exception
+is kept and NaN returned in its place, which stops any solver with `TerminationReason::NonFinite`. The exception is then
+raised in preference to the solver's error.
+*/
+struct PyFunction<'py> {
+    func: Bound<'py, PyAny>,
+    error: RefCell<Option<PyErr>>,
+}
+
+impl<'py> PyFunction<'py> {
+    fn new(func: Bound<'py, PyAny>) -> PyFunction<'py> {
+        PyFunction {
+            func,
+            error: RefCell::new(None),
+        }
+    }
+
+    fn call(&self, x: f64) -> f64 {
+        match self.func.call1((x,)).and_then(|y| y.extract::<f64>()) {
+            Ok(y) => y,
+            Err(err) => {
+                self.error.borrow_mut().get_or_insert(err);
+                f64::NAN
+            }
+        }
+    }
+
+    /// The exception raised by the callable, if there was one.
+    fn take_error(&self) -> Option<PyErr> {
+        self.error.borrow_mut().take()
+    }
+}
+
+/// Converts the outcome of a search to Python, raising any exception from `funcs` first.
+fn finish<T>(res: Result<T, RootError>, funcs: &[&PyFunction]) -> PyResult<T> {
+    if let Some(err) = funcs.iter().find_map(|func| func.take_error()) {
+        return Err(err);
+    }
+    res.map_err(|err| RootFindingError::new_err(err.to_string()))
+}
+
+/// A root, with every iterate computed on the way to it, the residual at each, and the number of function evaluations
+/// made.
+#[pyclass(name = "RootResult", get_all, skip_from_py_object)]
+#[derive(Debug, Clone)]
+pub struct PyRootResult {
+    pub root: f64,
+    pub history: Vec<f64>,
+    pub residuals: Vec<f64>,
+    pub evaluations: usize,
+}
+
+#[pymethods]
+impl PyRootResult {
+    /// The iterates as CSV, as written by `RootResult::to_csv`.
+    fn to_csv(&self) -> String {
+        root_search::RootResult::from(self.clone()).to_csv()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RootResult(root={}, iterations={}, evaluations={})",
+            self.root,
+            self.history.len(),
+            self.evaluations
+        )
+    }
+}
+
+impl From<root_search::RootResult> for PyRootResult {
+    fn from(res: root_search::RootResult) -> PyRootResult {
+        PyRootResult {
+            root: res.root,
+            history: res.history,
+            residuals: res.residuals,
+            evaluations: res.evaluations,
+        }
+    }
+}
+
+impl From<PyRootResult> for root_search::RootResult {
+    fn from(res: PyRootResult) -> root_search::RootResult {
+        root_search::RootResult {
+            root: res.root,
+            history: res.history,
+            residuals: res.residuals,
+            evaluations: res.evaluations,
+        }
+    }
+}
+
+/// The errors, error ratios and order estimates of a sequence of iterates, as in `analysis::ConvergenceReport`.
+#[pyclass(name = "ConvergenceReport", get_all, skip_from_py_object)]
+#[derive(Debug, Clone)]
+pub struct PyConvergenceReport {
+    pub errors: Vec<f64>,
+    pub ratios: Vec<Option<f64>>,
+    pub orders: Vec<Option<f64>>,
+    pub order: Option<f64>,
+    pub error_constant: Option<f64>,
+}
+
+/// The budget of a search, with `max_duration` in seconds.
+fn budget(
+    max_iter: usize,
+    max_evals: Option<usize>,
+    max_duration: Option<f64>,
+) -> PyResult<Budget> {
+    let budget: Budget = Budget::new(max_iter, max_evals);
+    match max_duration {
+        Some(secs) => Duration::try_from_secs_f64(secs)
+            .map(|max_duration| budget.with_max_duration(max_duration))
+            .map_err(|err| PyValueError::new_err(err.to_string())),
+        None => Ok(budget),
+    }
+}
+
+/// Binary search for a root of `func` over `[a, b]`, to within `tol`, returning the result and the final bracket.
+#[pyfunction]
+#[pyo3(signature = (func, a, b, tol, max_iter=100, max_evals=None, max_duration=None))]
+fn binary(
+    func: Bound<PyAny>,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+    max_evals: Option<usize>,
+    max_duration: Option<f64>,
+) -> PyResult<(PyRootResult, (f64, f64))> {
+    let budget: Budget = budget(max_iter, max_evals, max_duration)?;
+    let func: PyFunction = PyFunction::new(func);
+    let call: &ContinuousFunction = &|x: f64| func.call(x);
+    let bracket: Result<Bracket, &'static str> = Bracket::new(call, a, b);
+    if let Some(err) = func.take_error() {
+        return Err(err);
+    }
+    let bracket: Bracket = bracket.map_err(PyValueError::new_err)?;
+    finish(root_search::binary(call, bracket, tol, budget), &[&func]).map(|res| {
+        (
+            PyRootResult::from(res.result),
+            (res.bracket.lo(), res.bracket.hi()),
+        )
+    })
+}
+
+/// Fixed point iteration of `func` from `x0`.
+#[pyfunction]
+#[pyo3(signature = (func, x0, tol, max_iter=100, max_evals=None, max_duration=None))]
+fn fixed_point(
+    func: Bound<PyAny>,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+    max_evals: Option<usize>,
+    max_duration: Option<f64>,
+) -> PyResult<PyRootResult> {
+    let budget: Budget = budget(max_iter, max_evals, max_duration)?;
+    let func: PyFunction = PyFunction::new(func);
+    let res = root_search::fixed_point(&|x: f64| func.call(x), x0, tol, budget);
+    finish(res, &[&func]).map(PyRootResult::from)
+}
+
+/// Newton-Raphson iteration on `func`, with derivative `deriv`, from `x0`.
+#[pyfunction]
+#[pyo3(signature = (func, deriv, x0, tol, max_iter=100, max_evals=None, max_duration=None))]
+fn newton(
+    func: Bound<PyAny>,
+    deriv: Bound<PyAny>,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+    max_evals: Option<usize>,
+    max_duration: Option<f64>,
+) -> PyResult<PyRootResult> {
+    let budget: Budget = budget(max_iter, max_evals, max_duration)?;
+    let func: PyFunction = PyFunction::new(func);
+    let deriv: PyFunction = PyFunction::new(deriv);
+    let (call, call_deriv) = (|x: f64| func.call(x), |x: f64| deriv.call(x));
+    let newton: BoxedFn = functional::x_minus(functional::newton_raphson(call, call_deriv));
+    let res = root_search::fixed_point(&newton, x0, tol, budget);
+    finish(res, &[&func, &deriv]).map(PyRootResult::from)
+}
+
+/// Estimates the order of convergence of the iterates `history` to `root`.
+#[pyfunction]
+fn estimate_order(history: Vec<f64>, root: f64) -> PyConvergenceReport {
+    let report = analysis::estimate_order(&history, root);
+    PyConvergenceReport {
+        errors: report.errors,
+        ratios: report.ratios,
+        orders: report.orders,
+        order: report.order,
+        error_constant: report.error_constant,
+    }
+}
+
+/// Formats the iterates `history` converging to `root` as a Markdown table, or with `latex=True` a LaTeX one.
+#[pyfunction]
+#[pyo3(signature = (history, root, precision=6, latex=false))]
+fn table(history: Vec<f64>, root: f64, precision: usize, latex: bool) -> String {
+    let options = TableOptions {
+        format: if latex {
+            TableFormat::Latex
+        } else {
+            TableFormat::Markdown
+        },
+        precision,
+        ..Default::default()
+    };
+    report::table(&history, root, &options)
+}
+
+/// The `root_finding` Python module.
+#[pymodule]
+fn root_finding(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<PyRootResult>()?;
+    m.add_class::<PyConvergenceReport>()?;
+    m.add("RootFindingError", m.py().get_type::<RootFindingError>())?;
+    m.add_function(wrap_pyfunction!(python::binary, m)?)?;
+    m.add_function(wrap_pyfunction!(python::fixed_point, m)?)?;
+    m.add_function(wrap_pyfunction!(python::newton, m)?)?;
+    m.add_function(wrap_pyfunction!(python::estimate_order, m)?)?;
+    m.add_function(wrap_pyfunction!(python::table, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,408 @@
+/*!
+Formatted reports of the iterates computed by the root-finders, for write-ups.
+
+Functions
+---------
+* `table` : Formats a sequence of iterates as a Markdown or LaTeX table of iterates, errors, error ratios and orders.
+* `result_table` : Formats the history of a `RootResult` as a table, measuring errors from its root.
+* `plot_convergence` : Plots the error against iteration count of root searches. Requires the `plot` feature.
+* `plot_basins` : Draws the basins of attraction of a Newton fractal. Requires the `plot` feature.
+
+Types
+-----
+* `TableFormat` : The markup language of a table.
+* `TableOptions` : How to format a table.
+*/
+
+#[cfg(feature = "plot")]
+use analysis::NewtonFractal;
+use analysis::{estimate_order, ConvergenceReport};
+#[cfg(feature = "plot")]
+use plotters::coord::Shift;
+#[cfg(feature = "plot")]
+use plotters::prelude::*;
+use root_search::RootResult;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "plot")]
+use std::error::Error;
+use std::fmt::Display;
+#[cfg(feature = "plot")]
+use std::path::Path;
+use Float;
+
+/// The markup language of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableFormat {
+    /// A GitHub-flavoured Markdown table, as used in `README.md`.
+    Markdown,
+    /// A LaTeX `tabular` environment.
+    Latex,
+}
+
+/// How to format a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableOptions {
+    pub format: TableFormat,
+    /// The number of decimal places to print.
+    pub precision: usize,
+    /// If set to `(first, last)`, only the first `first` and last `last` rows are printed, separated by a row of ellipses.
+    pub truncate: Option<(usize, usize)>,
+}
+
+impl Default for TableOptions {
+    fn default() -> TableOptions {
+        TableOptions {
+            format: TableFormat::Markdown,
+            precision: 6,
+            truncate: None,
+        }
+    }
+}
+
+/**
+Formats a sequence of iterates as a table with columns for the iterate, its error, the error ratio and the estimated order of convergence.
+
+Parameters
+----------
+* `history` : The iterates `x_0, x_1, ...`.
+* `root` : The true root, from which errors are measured.
+* `options` : How to format the table.
+
+Returns
+-------
+* `String` : The formatted table.
+
+Examples
+--------
+In this example, we tabulate the first 2 and last 2 iterates of Newton-Raphson on `trig`.
+```rust
+# use root_finding::*;
+let newton = functional::x_minus(functional::newton_raphson(&test_function::trig, &test_func_derivative::trig));
+let res = root_search::fixed_point(&newton, -4.0, 1e-10, root_search::Budget::new(100, None))?;
+let options = report::TableOptions { truncate: Some((2, 2)), ..Default::default() };
+println!("{}", report::table(&res.history, res.root, &options));
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn table<T: Float + Display>(history: &[T], root: T, options: &TableOptions) -> String {
+    let report: ConvergenceReport<T> = estimate_order(history, root);
+    let cell = |x: Option<T>| -> String {
+        match x {
+            Some(x) if x.is_finite() => format!("{:.*}", options.precision, x),
+            _ => String::new(),
+        }
+    };
+
+    let mut rows: Vec<Vec<String>> = (0..history.len())
+        .map(|n| {
+            vec![
+                n.to_string(),
+                cell(Some(history[n])),
+                cell(Some(report.errors[n])),
+                cell(report.ratios[n]),
+                cell(report.orders[n]),
+            ]
+        })
+        .collect();
+    if let Some((first, last)) = options.truncate {
+        if first + last < rows.len() {
+            let ellipsis: &str = match options.format {
+                TableFormat::Markdown => "⋮",
+                TableFormat::Latex => "\\vdots",
+            };
+            let tail: Vec<Vec<String>> = rows.split_off(rows.len() - last);
+            rows.truncate(first);
+            rows.push(vec![ellipsis.to_string(); 5]);
+            rows.extend(tail);
+        }
+    }
+
+    match options.format {
+        TableFormat::Markdown => markdown(&rows),
+        TableFormat::Latex => latex(&rows),
+    }
+}
+
+/**
+Formats the history of a `RootResult` as a table, measuring errors from the root it found.
+
+See `table` for details.
+*/
+pub fn result_table<T: Float + Display>(result: &RootResult<T>, options: &TableOptions) -> String {
+    table(&result.history, result.root, options)
+}
+
+fn markdown(rows: &[Vec<String>]) -> String {
+    let header: Vec<String> = [
+        "$N$",
+        "$x_N$",
+        "$\\epsilon_N$",
+        "$\\epsilon_N/\\epsilon_{N-1}$",
+        "$p_N$",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .fold(header[i].chars().count(), usize::max)
+        })
+        .collect();
+    let line = |cells: &[String]| -> String {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+
+    let mut out: String = line(&header);
+    out.push_str(&line(
+        &widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<String>>(),
+    ));
+    for row in rows {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+fn latex(rows: &[Vec<String>]) -> String {
+    let mut out: String = String::from("\\begin{tabular}{rrrrr}\n\\hline\n");
+    out.push_str(
+        "$N$ & $x_N$ & $\\epsilon_N$ & $\\epsilon_N/\\epsilon_{N-1}$ & $p_N$ \\\\\n\\hline\n",
+    );
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|cell| {
+                if cell.starts_with('\\') {
+                    format!("${}$", cell)
+                } else {
+                    cell.clone()
+                }
+            })
+            .collect();
+        out.push_str(&format!("{} \\\\\n", cells.join(" & ")));
+    }
+    out.push_str("\\hline\n\\end{tabular}\n");
+    out
+}
+
+/**
+Plots `log10|x_N - x_*|` against `N` for one or more root searches, measuring each from the root it found.
+
+The backend is chosen by the extension of `path`: `.svg` files are drawn as SVG, and anything else as a bitmap (e.g. PNG).
+Runs are labelled by their position in `results`; use `plot_convergence_labelled` to name them.
+
+Only available with the `plot` feature.
+
+Parameters
+----------
+* `results` : The root searches to plot.
+* `path` : The file to draw to.
+
+Errors
+------
+* If the file can't be drawn or written.
+
+Examples
+--------
+In this example, we compare bisection against Newton-Raphson on `trig`.
+```rust,no_run
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::trig, -4.0, 0.0)?;
+let bisection = root_search::binary(&test_function::trig, bracket, 1e-12, root_search::Budget::unlimited())?.result;
+let newton = functional::x_minus(functional::newton_raphson(&test_function::trig, &test_func_derivative::trig));
+let newton = root_search::fixed_point(&newton, -4.0, 1e-12, root_search::Budget::new(100, None))?;
+report::plot_convergence_labelled(
+    &[("Bisection", &bisection), ("Newton-Raphson", &newton)],
+    "convergence.png",
+)?;
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+#[cfg(feature = "plot")]
+pub fn plot_convergence<P: AsRef<Path>>(
+    results: &[RootResult<f64>],
+    path: P,
+) -> Result<(), Box<dyn Error>> {
+    let labels: Vec<String> = (0..results.len()).map(|i| format!("Run {}", i)).collect();
+    let series: Vec<(&str, &RootResult<f64>)> =
+        labels.iter().map(|s| s.as_str()).zip(results).collect();
+    plot_convergence_labelled(&series, path)
+}
+
+/**
+Plots `log10|x_N - x_*|` against `N` for one or more labelled root searches.
+
+See `plot_convergence` for details. Only available with the `plot` feature.
+*/
+#[cfg(feature = "plot")]
+pub fn plot_convergence_labelled<P: AsRef<Path>>(
+    series: &[(&str, &RootResult<f64>)],
+    path: P,
+) -> Result<(), Box<dyn Error>> {
+    let path: &Path = path.as_ref();
+    if path.extension().is_some_and(|ext| ext == "svg") {
+        draw_convergence(
+            SVGBackend::new(path, (800, 600)).into_drawing_area(),
+            series,
+        )
+    } else {
+        draw_convergence(
+            BitMapBackend::new(path, (800, 600)).into_drawing_area(),
+            series,
+        )
+    }
+}
+
+/// The points `(N, log10|x_N - x_*|)` of a root search, omitting exact zeros.
+#[cfg(feature = "plot")]
+fn log_errors(result: &RootResult<f64>) -> Vec<(f64, f64)> {
+    result
+        .history
+        .iter()
+        .enumerate()
+        .filter(|&(_, &x)| x != result.root)
+        .map(|(n, &x)| (n as f64, (x - result.root).abs().log10()))
+        .collect()
+}
+
+#[cfg(feature = "plot")]
+fn draw_convergence<DB: DrawingBackend>(
+    area: DrawingArea<DB, Shift>,
+    series: &[(&str, &RootResult<f64>)],
+) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let points: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|(_, result)| log_errors(result))
+        .collect();
+    let all = || points.iter().flatten();
+    let max_n: f64 = all().map(|p| p.0).fold(1.0, f64::max);
+    let min_err: f64 = all().map(|p| p.1).fold(0.0, f64::min).floor();
+    let max_err: f64 = all().map(|p| p.1).fold(min_err + 1.0, f64::max).ceil();
+
+    area.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&area)
+        .caption("Convergence", ("sans-serif", 24))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..max_n, min_err..max_err)?;
+    chart
+        .configure_mesh()
+        .x_desc("N")
+        .y_desc("log10|error|")
+        .draw()?;
+
+    for (i, ((label, _), points)) in series.iter().zip(&points).enumerate() {
+        let colour = Palette99::pick(i).to_rgba();
+        chart
+            .draw_series(LineSeries::new(
+                points.iter().cloned(),
+                colour.stroke_width(2),
+            ))?
+            .label(*label)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], colour));
+    }
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    area.present()?;
+    Ok(())
+}
+
+/**
+Draws the basins of attraction of a Newton fractal, one pixel per starting point.
+
+Each root is given its own colour, shaded darker the more iterations were needed to reach it; starting points which
+didn't converge are black. As with `plot_convergence`, `.svg` files are drawn as SVG and anything else as a bitmap.
+
+Only available with the `plot` feature.
+
+Parameters
+----------
+* `fractal` : The basins to draw, as computed by `analysis::newton_basins`.
+* `path` : The file to draw to.
+
+Errors
+------
+* If the file can't be drawn or written.
+
+Examples
+--------
+```rust,no_run
+# use root_finding::*;
+let poly = polynomial::Polynomial::new(vec![-1.0, 0.0, 0.0, 1.0]);
+let fractal = analysis::newton_basins(&poly, (-2.0, 2.0), (-2.0, 2.0), (600, 600), 1e-10, 100)?;
+report::plot_basins(&fractal, "fractal.png")?;
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+#[cfg(feature = "plot")]
+pub fn plot_basins<P: AsRef<Path>>(fractal: &NewtonFractal, path: P) -> Result<(), Box<dyn Error>> {
+    let path: &Path = path.as_ref();
+    let rows: u32 = fractal.basins.len() as u32;
+    let cols: u32 = fractal.basins.first().map_or(0, |row| row.len()) as u32;
+    if path.extension().is_some_and(|ext| ext == "svg") {
+        draw_basins(
+            SVGBackend::new(path, (cols, rows)).into_drawing_area(),
+            fractal,
+        )
+    } else {
+        draw_basins(
+            BitMapBackend::new(path, (cols, rows)).into_drawing_area(),
+            fractal,
+        )
+    }
+}
+
+#[cfg(feature = "plot")]
+fn draw_basins<DB: DrawingBackend>(
+    area: DrawingArea<DB, Shift>,
+    fractal: &NewtonFractal,
+) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let max_iter: usize = fractal
+        .basins
+        .iter()
+        .flatten()
+        .filter(|basin| basin.root.is_some())
+        .map(|basin| basin.iterations)
+        .fold(1, usize::max);
+
+    area.fill(&BLACK)?;
+    for (y, row) in fractal.basins.iter().enumerate() {
+        for (x, basin) in row.iter().enumerate() {
+            if let Some(root) = basin.root {
+                let shade: f64 = 1.0 - 0.8 * basin.iterations as f64 / max_iter as f64;
+                let (r, g, b) = Palette99::pick(root).to_rgba().rgb();
+                let scale = |c: u8| -> u8 { (c as f64 * shade) as u8 };
+                area.draw_pixel(
+                    (x as i32, y as i32),
+                    &RGBColor(scale(r), scale(g), scale(b)),
+                )?;
+            }
+        }
+    }
+
+    area.present()?;
+    Ok(())
+}
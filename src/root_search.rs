@@ -4,127 +4,2777 @@ A collection of algorithms for use in root-finding.
 Functions
 ---------
 * `binary` : Binary search, a.k.a interval bisection.
+* `chandrupatla` : Chandrupatla's algorithm, choosing between bisection and inverse quadratic interpolation at each step.
+* `binary_noisy` : Binary search on a function with noisy values, stopping where the noise hides its sign.
+* `chandrupatla_noisy` : Chandrupatla's algorithm on a function with noisy values.
+* `binary_extrapolated` : Binary search, with an extrapolated root and an a-posteriori error estimate from its midpoints.
+* `binary_verified` : Binary search with interval arithmetic, giving an interval certain to contain a root. Requires the `interval` feature.
 * `fixed_point` : Fixed point iteration.
+* `relaxed_fixed_point` : Fixed point iteration with a fixed or adaptive (Wegstein) relaxation parameter.
+* `hybrid_newton` : A robust global method, switching to Newton-Raphson once close to the root.
+* `newton_multiplicity` : Newton-Raphson iteration modified for roots of known or detected multiplicity.
+* `newton_complex` : Newton-Raphson iteration in the complex plane, for complex roots.
+* `estimate_multiplicity` : Estimates the multiplicity of a root from Newton-Raphson iterates.
+* `find_all_roots` : Finds every root in an interval, by scanning for sign changes and bisecting.
+* `bracket_outward` : Grows an interval around an initial guess until the function changes sign over it.
+* `polish` : Refines a root estimate to the limit of floating-point precision.
+* `invert` : Solves `F(x) = y`, e.g. to evaluate an inverse function or a quantile.
+* `with_restarts` : Reruns a root-finder from random initial guesses while it fails, reproducibly from a seed.
+* `multi_start` : Runs a root-finder from many initial guesses in parallel, collecting the distinct roots found. Requires the `rayon` feature.
+
+Types
+-----
+* `RootResult` : A root, together with the iterates computed on the way to it.
+* `RootError` : A failed root search, with the iterates computed before it stopped.
+* `TerminationReason` : Why a root search stopped without finding a root.
+* `Budget` : The iterations, function evaluations and time a root search may spend.
+* `Bracket` : An interval over which a function changes sign.
+* `BracketedResult` : The result of `binary`, with its final bracket.
+* `Relaxation` : How `relaxed_fixed_point` chooses its relaxation parameter.
+* `GlobalPhase` : The method `hybrid_newton` starts with.
+* `NoisyResult` : The result of `binary_noisy` or `chandrupatla_noisy`, with the accuracy achievable given the noise.
+* `Extrapolation` : A root extrapolated from the iterates of a search, with an estimate of its error.
+* `ExtrapolatedResult` : The result of `binary_extrapolated`, with the extrapolated root.
+* `HybridResult` : The result of `hybrid_newton`, with the iterate at which it switched to Newton-Raphson.
+* `CountedFunction` : A function wrapped to count its evaluations.
+* `DomainOrGuess` : Where `invert` should look for a solution.
+* `InvertError` : Why `invert` failed: no bracket was found, or the search of it failed.
+* `RestartPolicy` : How `with_restarts` draws new initial guesses, and how many.
+* `Attempt` : One run of the root-finder made by `with_restarts`.
+* `Restarted` : Every attempt made by `with_restarts`.
+* `MultiStart` : The distinct roots found by `multi_start`, with the outcome from each initial guess.
+
+All algorithms are generic over `num::Float`, so may be run with e.g. `f32` for speed or `f64` for accuracy. They take
+their functions as any `ObjectiveFn`, by generic parameter, so a closure passed directly is called without dynamic
+dispatch; `ContinuousFunction` trait objects are accepted too, for functions chosen at runtime.
+
+Domains
+-------
+Functions such as `ln(x)` are only defined on part of the real line. The algorithms check every function value they
+compute, and stop with `TerminationReason::NonFinite` at the first that is NaN or infinite, rather than carrying it
+into later iterates. The one exception is the function values at the endpoints of a `Bracket`, which are only used for
+their sign, so may be infinite: `ln` can be searched over `[0, 2]`, since `ln(0) = -inf`, but not over `[-1, 2]`.
+Likewise `find_all_roots` skips the subdivision points at which the function is NaN, and the sub-intervals in which a
+search stops at a non-finite value, so can be run over a domain extending past where the function is defined.
+
+Residuals
+---------
+Alongside the iterates in `history`, a search records in `residuals` the value of the function at each iterate,
+`F(x_n)`, wherever it evaluates it anyway, so that the residuals can be studied without evaluating the function again,
+which would double the cost of an expensive function and is impossible for one with state or noise. The bracketing
+methods, `newton_multiplicity` and `hybrid_newton` record `F(x_n)`; `fixed_point` and `relaxed_fixed_point`, which only
+see the iterated function `g`, record the residual `g(x_n) - x_n` of the fixed point equation, which is also the step.
+If a search stops without evaluating the function at its last iterate, e.g. because its budget ran out, `residuals` is
+one shorter than `history`. The steps `x_{n+1} - x_n` follow from `history`, and are given by `RootResult::steps`.
+
+The residuals of the last few iterates also give a better estimate of the root than the last iterate alone:
+`RootResult::extrapolate` applies Richardson extrapolation to the iterates, regarded as a function of their residuals,
+to estimate the iterate at which the residual would be zero.
+
+Logging
+-------
+With the `tracing` feature, every solver runs in a `tracing` span named after it, emits a debug-level event for each
+iterate, and an info-level event summarising the outcome, so that a subscriber installed by the caller can follow the
+search without any changes here.
 */
 
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::cell::{Cell, RefCell};
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+use core::error::Error;
+use core::fmt;
+use core::time::Duration;
+#[cfg(feature = "interval")]
+use interval::Interval;
+use num::Complex;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::time::Instant;
+use BatchedFunction;
 use ContinuousFunction;
-use {compose_fn, signum};
+use Float;
+use ObjectiveFn;
+
+/// The sign of `x`, as `num::signum` but for any `Float`: unlike `Float::signum`, zero maps to zero.
+fn sgn<T: Float>(x: T) -> T {
+    if x.is_zero() {
+        T::zero()
+    } else {
+        x.signum()
+    }
+}
+
+/// The outcome of a successful root search.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RootResult<T = f64> {
+    /// The estimated root.
+    pub root: T,
+    /// Every iterate computed on the way to `root`, in order; for bracketing methods, the midpoints.
+    pub history: Vec<T>,
+    /// The residual at each iterate of `history`, in order, as described in the module documentation.
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
+    pub residuals: Vec<T>,
+    /// The number of function evaluations made, including evaluations of any derivatives.
+    pub evaluations: usize,
+}
+
+impl<T: Float> RootResult<T> {
+    /**
+    The step `x_{n+1} - x_n` from each iterate of `history` to the next, or to `root` from the last.
+
+    Examples
+    --------
+    In this example, the residuals and steps of Newton-Raphson on `trig` both shrink quadratically.
+    ```rust
+    # use root_finding::*;
+    use root_finding::root_search::Budget;
+    let res = root_search::newton_multiplicity(&test_function::trig, &test_func_derivative::trig, Some(1.0), -2.0, 1e-12, Budget::unlimited())?;
+    assert_eq!(res.history.len(), res.residuals.len());
+    let (residuals, steps) = (&res.residuals, res.steps());
+    for n in 2..steps.len() {
+        assert!(residuals[n].abs() < residuals[n - 1].abs());
+        assert!(steps[n].abs() < 10.0 * steps[n - 1].powi(2));
+    }
+    # Ok::<(), root_search::RootError>(())
+    ```
+    */
+    pub fn steps(&self) -> Vec<T> {
+        let mut steps: Vec<T> = steps(&self.history);
+        if let Some(&last) = self.history.last() {
+            steps.push(self.root - last);
+        }
+        steps
+    }
+
+    /**
+    A refined estimate of the root, extrapolated from the last three iterates and their residuals, with an
+    a-posteriori estimate of its error.
+
+    Regarding each iterate `x_n` as a function of its residual `F(x_n)`, Richardson extrapolation (Neville's scheme)
+    to `F = 0`, first through the last two iterates and then the last three, gives two estimates of the root, of
+    increasing order; the second is returned, and the difference between them estimates its error. Near a simple root,
+    where `F` is smooth, the error of the extrapolated root falls as the cube of the spread of the iterates, so for the
+    midpoints of a bisection it is far smaller than the final bracket. The estimate is only as good as the residuals,
+    so is not to be trusted where they are dominated by rounding or noise.
+
+    Returns
+    -------
+    * `Option<Extrapolation<T>>` : The extrapolated root and its estimated error; or `None` if fewer than three
+      residuals were recorded, two of the last three are equal, or the extrapolation is not finite.
+
+    Examples
+    --------
+    In this example, the extrapolated root of `trig` from 20 steps of bisection is accurate to 1e-12, and its estimated
+    error is far smaller than the final bracket, of width 4e-6, while still bounding the true error.
+    ```rust
+    # use root_finding::*;
+    use root_finding::root_search::{Bracket, Budget};
+    let bracket = Bracket::new(&test_function::trig, -4.0, 0.0)?;
+    let res = root_search::binary_extrapolated(&test_function::trig, bracket, 4e-6, Budget::unlimited())?;
+    let extrapolation = res.result.extrapolate().unwrap();
+    assert!((extrapolation.root + 2.8832368725582835).abs() < 1e-12);
+    assert!((extrapolation.root + 2.8832368725582835).abs() <= extrapolation.error);
+    assert!(extrapolation.error < 1e-9);
+    # Ok::<(), Box<dyn std::error::Error>>(())
+    ```
+    */
+    pub fn extrapolate(&self) -> Option<Extrapolation<T>> {
+        if self.residuals.len() < 3 {
+            return None;
+        }
+        let n: usize = self.residuals.len();
+        let (x, f): (&[T], &[T]) = (&self.history[n - 3..n], &self.residuals[n - 3..]);
+        // The value at `F = 0` of the line through `(f_i, x_i)` and `(f_j, x_j)`, as a function of `F`.
+        let neville = |x_i: T, f_i: T, x_j: T, f_j: T| (f_j * x_i - f_i * x_j) / (f_j - f_i);
+        let linear: [T; 2] = [
+            neville(x[0], f[0], x[1], f[1]),
+            neville(x[1], f[1], x[2], f[2]),
+        ];
+        let root: T = neville(linear[0], f[0], linear[1], f[2]);
+        let error: T = (root - linear[1]).abs();
+        if root.is_finite() && error.is_finite() {
+            Some(Extrapolation { root, error })
+        } else {
+            None
+        }
+    }
+}
+
+/// The differences between consecutive values of `history`.
+fn steps<T: Float>(history: &[T]) -> Vec<T> {
+    history.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+/// Why a root search stopped without finding a root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TerminationReason {
+    /// The maximum number of iterations was reached.
+    MaxIterations,
+    /// The budget of function evaluations or time was spent.
+    BudgetExhausted,
+    /// The iterates were growing without bound.
+    Diverged,
+    /// The iterates entered a cycle of the given period.
+    Cycling { period: usize },
+    /// The function evaluated to NaN or infinity, at the last point of the history.
+    NonFinite,
+}
+
+/// A failed root search, with the iterates computed before it stopped.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RootError<T = f64> {
+    pub reason: TerminationReason,
+    /// Every iterate computed before the search stopped, in order.
+    pub history: Vec<T>,
+    /// The residual at each iterate of `history` at which one was computed, in order.
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
+    pub residuals: Vec<T>,
+    /// The number of function evaluations made, including evaluations of any derivatives.
+    pub evaluations: usize,
+}
+
+impl<T> RootError<T> {
+    /// A description of why the search stopped.
+    pub fn message(&self) -> &'static str {
+        match self.reason {
+            TerminationReason::MaxIterations => "Error: failed to converge!",
+            TerminationReason::BudgetExhausted => "Error: budget exhausted!",
+            TerminationReason::Diverged => "Error: iterates diverged!",
+            TerminationReason::Cycling { .. } => "Error: iterates entered a cycle!",
+            TerminationReason::NonFinite => "Error: function is not finite!",
+        }
+    }
+
+    /// The best estimate of the root when the search stopped, i.e. the last iterate, if there was one.
+    pub fn estimate(&self) -> Option<&T> {
+        self.history.last()
+    }
+
+    /// The point at which the function evaluated to NaN or infinity, if that is why the search stopped.
+    pub fn non_finite_at(&self) -> Option<&T> {
+        match self.reason {
+            TerminationReason::NonFinite => self.history.last(),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Float> RootError<T> {
+    /// The step `x_{n+1} - x_n` from each iterate of `history` to the next.
+    pub fn steps(&self) -> Vec<T> {
+        steps(&self.history)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RootError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.non_finite_at() {
+            Some(x) => write!(f, "Error: function is not finite at x = {}!", x),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for RootError<T> {}
+
+/**
+A function wrapped to count its evaluations.
+
+Every solver evaluates its functions through a `CountedFunction`, so that the cost of a search can be reported in
+`RootResult::evaluations` and bounded by an evaluation budget. It is also useful on its own, to count the evaluations
+of a function buried inside a functional such as `functional::newton_raphson`.
+
+Examples
+--------
+In this example, we count the evaluations of `trig` made by Newton-Raphson iteration.
+```rust
+# use root_finding::*;
+let trig = root_search::CountedFunction::new(&test_function::trig);
+let func = |x: f64| trig.call(x);
+let newton = functional::x_minus(functional::newton_raphson(&func, &test_func_derivative::trig));
+let res = root_search::fixed_point(&newton, -4.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(res.evaluations, trig.evaluations());
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub struct CountedFunction<'a, F: ?Sized + 'a = ContinuousFunction<'a>> {
+    func: &'a F,
+    count: Cell<usize>,
+}
+
+impl<'a, F: ?Sized> CountedFunction<'a, F> {
+    pub fn new(func: &'a F) -> CountedFunction<'a, F> {
+        CountedFunction {
+            func,
+            count: Cell::new(0),
+        }
+    }
+
+    /// Evaluates the function at `x`, counting the evaluation.
+    pub fn call<T>(&self, x: T) -> T
+    where
+        F: ObjectiveFn<T>,
+    {
+        self.count.set(self.count.get() + 1);
+        self.func.eval(x)
+    }
+
+    /// The number of evaluations made so far.
+    pub fn evaluations(&self) -> usize {
+        self.count.get()
+    }
+}
+
+/// The number of consecutive iterations over which both the step and the iterate must grow to declare divergence.
+const DIVERGENCE_STEPS: usize = 10;
+
+/// The fraction of the truncation error to which iterates are rounded before looking for cycles.
+const CYCLE_RESOLUTION: f64 = 1e-3;
+
+/// Brent's cycle detection, run on a sequence of values as they are computed.
+struct CycleDetector<T> {
+    tortoise: Option<T>,
+    power: usize,
+    lambda: usize,
+}
+
+impl<T: Float> CycleDetector<T> {
+    fn new() -> CycleDetector<T> {
+        CycleDetector {
+            tortoise: None,
+            power: 1,
+            lambda: 0,
+        }
+    }
+
+    /// Records the next value of the sequence, returning the period if it has been seen `period` values ago.
+    fn observe(&mut self, val: T) -> Option<usize> {
+        if self.tortoise == Some(val) {
+            return Some(self.lambda);
+        }
+        if self.tortoise.is_none() || self.lambda == self.power {
+            if self.tortoise.is_some() {
+                self.power *= 2;
+            }
+            self.tortoise = Some(val);
+            self.lambda = 0;
+        }
+        self.lambda += 1;
+        None
+    }
+}
+
+/// Records the outcome of a search as an info-level `tracing` event.
+#[cfg(feature = "tracing")]
+fn traced<T: Float>(
+    res: Result<RootResult<T>, RootError<T>>,
+) -> Result<RootResult<T>, RootError<T>> {
+    match res {
+        Ok(ref res) => trace_info!(
+            root = res.root.to_f64(),
+            iterations = res.history.len(),
+            evaluations = res.evaluations,
+            "converged"
+        ),
+        Err(ref err) => trace_info!(
+            reason = ?err.reason,
+            iterations = err.history.len(),
+            evaluations = err.evaluations,
+            "failed"
+        ),
+    }
+    res
+}
+
+/// Records the outcome of a search, with the `tracing` feature.
+#[cfg(not(feature = "tracing"))]
+fn traced<T>(res: Result<RootResult<T>, RootError<T>>) -> Result<RootResult<T>, RootError<T>> {
+    res
+}
+
+/**
+The resources a solver may spend before giving up: iterations, function evaluations, and time.
+
+A solver that runs out of iterations stops with `TerminationReason::MaxIterations`, and one that runs out of
+evaluations or time with `TerminationReason::BudgetExhausted`; either way, its best estimate of the root so far is
+`RootError::estimate`. The time limit is only enforced with the `std` feature, since without it there is no clock.
+
+Examples
+--------
+In this example, Newton-Raphson on `trig` is allowed 4 evaluations, and stops with an estimate correct to 0.1.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Budget, TerminationReason};
+let newton = functional::x_minus(functional::newton_raphson(&test_function::trig, &test_func_derivative::trig));
+let err = root_search::fixed_point(&newton, -4.0, 1e-12, Budget::new(100, Some(4))).unwrap_err();
+assert_eq!(TerminationReason::BudgetExhausted, err.reason);
+assert_eq!(-2.9, (err.estimate().unwrap()*10.0).round()/10.0);
+```
+---
+In this example, a search is given a second at most.
+```rust
+# use root_finding::*;
+use root_finding::root_search::Budget;
+use std::time::Duration;
+let budget = Budget::new(1000, None).with_max_duration(Duration::from_secs(1));
+let res = root_search::fixed_point(&(|x: f64| x.cos()), 1.0, 1e-12, budget)?;
+assert_eq!(0.739, (res.root*1000.0).round()/1000.0);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Budget {
+    /// The maximum number of iterations, each computing one new iterate, after which every solver stops with
+    /// `TerminationReason::MaxIterations`. This is highly dependent on both the rate of convergence and the truncation
+    /// error.
+    pub max_iter: usize,
+    /// The maximum number of function evaluations, including evaluations of any derivatives, or `None` for no limit.
+    pub max_evals: Option<usize>,
+    /// The maximum time to search for, or `None` for no limit.
+    pub max_duration: Option<Duration>,
+}
+
+impl Budget {
+    /// A budget of `max_iter` iterations and `max_evals` evaluations, with no time limit.
+    pub fn new(max_iter: usize, max_evals: Option<usize>) -> Budget {
+        Budget {
+            max_iter,
+            max_evals,
+            max_duration: None,
+        }
+    }
+
+    /// No limit at all, for methods certain to terminate, such as `binary`.
+    pub fn unlimited() -> Budget {
+        Budget::new(usize::MAX, None)
+    }
+
+    /// The same budget, limited to `max_duration`.
+    pub fn with_max_duration(self, max_duration: Duration) -> Budget {
+        Budget {
+            max_duration: Some(max_duration),
+            ..self
+        }
+    }
+
+    /// Whether `evaluations` more evaluations, on top of `used`, would exceed the budget, or its time has run out.
+    fn exhausted(&self, used: usize, evaluations: usize, clock: &Clock) -> bool {
+        self.max_evals
+            .is_some_and(|max_evals| used + evaluations > max_evals)
+            || clock.expired()
+    }
+
+    /// What is left of the budget for a later phase of a search, after `evaluations` evaluations in earlier ones.
+    fn remaining(&self, evaluations: usize, clock: &Clock) -> Budget {
+        Budget {
+            max_iter: self.max_iter,
+            max_evals: self
+                .max_evals
+                .map(|max_evals| max_evals.saturating_sub(evaluations)),
+            max_duration: clock.remaining(),
+        }
+    }
+}
+
+/// The time at which the `max_duration` of a budget runs out, measured from the start of a search.
+struct Clock {
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+}
+
+impl Clock {
+    /// A clock which never runs out.
+    fn unlimited() -> Clock {
+        Clock {
+            #[cfg(feature = "std")]
+            deadline: None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn start(budget: &Budget) -> Clock {
+        Clock {
+            deadline: budget
+                .max_duration
+                .and_then(|max_duration| Instant::now().checked_add(max_duration)),
+        }
+    }
+
+    /// Starts a clock which never runs out, without the `std` feature.
+    #[cfg(not(feature = "std"))]
+    fn start(_budget: &Budget) -> Clock {
+        Clock::unlimited()
+    }
+
+    #[cfg(feature = "std")]
+    fn expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn expired(&self) -> bool {
+        false
+    }
+
+    /// The time left before the deadline, or `None` if there isn't one.
+    #[cfg(feature = "std")]
+    fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn remaining(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/**
+An interval `[lo, hi]` over which a continuous function changes sign, and so contains a root.
+
+The constructors guarantee that `lo <= hi` and that `f_lo` and `f_hi` do not have the same (non-zero) sign, so any
+bracket is a rigorous bound on the location of a root: the root lies within `width()/2` of `midpoint()`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::trig, -2.0, -3.0)?;
+assert_eq!((-3.0, -2.0), bracket.domain());
+assert!(root_search::Bracket::new(&test_function::trig, 0.0, 1.0).is_err());
+# Ok::<(), &'static str>(())
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        try_from = "BracketValues<T>",
+        bound(deserialize = "T: Float + Deserialize<'de>")
+    )
+)]
+pub struct Bracket<T = f64> {
+    lo: T,
+    hi: T,
+    f_lo: T,
+    f_hi: T,
+}
+
+/// The fields of a `Bracket` as deserialised, before its sign change is checked.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct BracketValues<T> {
+    lo: T,
+    hi: T,
+    f_lo: T,
+    f_hi: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Float> TryFrom<BracketValues<T>> for Bracket<T> {
+    type Error = &'static str;
+
+    fn try_from(values: BracketValues<T>) -> Result<Bracket<T>, &'static str> {
+        Bracket::from_values(values.lo, values.hi, values.f_lo, values.f_hi)
+    }
+}
+
+impl<T: Float> Bracket<T> {
+    /**
+    Creates a bracket from two endpoints, in either order, evaluating the function at each.
+
+    Errors
+    ------
+    * If the function doesn't change sign at the endpoints
+    */
+    pub fn new<F: ObjectiveFn<T> + ?Sized>(
+        func: &F,
+        a: T,
+        b: T,
+    ) -> Result<Bracket<T>, &'static str> {
+        Bracket::from_values(a, b, func.eval(a), func.eval(b))
+    }
+
+    /**
+    Creates a bracket from two endpoints, in either order, and the function values there.
+
+    The function values may be infinite, as at the boundary of a half-open domain, but not NaN.
+
+    Errors
+    ------
+    * If the function values don't differ in sign, or either is NaN
+    */
+    pub fn from_values(a: T, b: T, f_a: T, f_b: T) -> Result<Bracket<T>, &'static str> {
+        if sgn(f_a) * sgn(f_b) > T::zero() || f_a.is_nan() || f_b.is_nan() {
+            return Err("Error: no sign change at endpoints!");
+        }
+        if a <= b {
+            Ok(Bracket {
+                lo: a,
+                hi: b,
+                f_lo: f_a,
+                f_hi: f_b,
+            })
+        } else {
+            Ok(Bracket {
+                lo: b,
+                hi: a,
+                f_lo: f_b,
+                f_hi: f_a,
+            })
+        }
+    }
+
+    /// A bracket of zero width around an exact root.
+    fn exact(root: T) -> Bracket<T> {
+        Bracket {
+            lo: root,
+            hi: root,
+            f_lo: T::zero(),
+            f_hi: T::zero(),
+        }
+    }
+
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+
+    /// The function value at `lo`.
+    pub fn f_lo(&self) -> T {
+        self.f_lo
+    }
+
+    /// The function value at `hi`.
+    pub fn f_hi(&self) -> T {
+        self.f_hi
+    }
+
+    pub fn width(&self) -> T {
+        self.hi - self.lo
+    }
+
+    pub fn midpoint(&self) -> T {
+        self.lo + self.width() / (T::one() + T::one())
+    }
+
+    /// The bracket as a `(start, end)` domain.
+    pub fn domain(&self) -> (T, T) {
+        (self.lo, self.hi)
+    }
+}
+
+/// The outcome of a successful `binary` search.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "T: Float + Serialize + for<'a> Deserialize<'a>")
+)]
+pub struct BracketedResult<T = f64> {
+    /// The midpoint of the final bracket, with every midpoint computed and the residual at each.
+    pub result: RootResult<T>,
+    /// The final bracket, a rigorous bound on the root, or of zero width at an exact root.
+    pub bracket: Bracket<T>,
+}
 
 /**
 Find a root of a continuous function using binary search.
 
 Parameters
 ----------
-* `func` : A continuous function with a sign change over the given domain.
-* `domain` : The start and end points of the search interval. Note that `func` must be computable over the entire domain, including end points.
+* `func` : A continuous function with a sign change over the given bracket.
+* `bracket` : The search interval. Note that `func` must be computable over the entire bracket, including end points.
 * `trunc_err` : A float representing the acceptable truncation error for the search; e.g. `trunc_err=1` will result in finding the root +-1.
+* `budget` : The maximum number of midpoints, evaluations of `func`, and time to spend. Binary search always terminates,
+  since it stops once the endpoints of the bracket are adjacent floats, even if `trunc_err` is smaller than that, so
+  this may be `Budget::unlimited()`.
 
 Returns
 -------
-* `f64` : A floating point representing the root of the function.
+* `BracketedResult<T>` : The final bracket, and its midpoint as a `RootResult` with every midpoint computed and the
+  residual at each. The final bracket contains a root and is narrower than `trunc_err`, or as narrow as the floats
+  allow, so the midpoint is within `trunc_err/2` of the root; it has zero width if `func` vanishes exactly at one of
+  the points evaluated.
 
 Errors
 ------
-* If the function doesn't change sign at the endpoints
+* `RootError<T>` : If `func` is NaN or infinite at a midpoint, returns the midpoints computed, ending with that one. If
+  the budget runs out first, returns the midpoints computed, followed by the midpoint of the remaining bracket as the
+  best estimate of the root.
 
 Examples
 --------
 In this example, `root_search::binary` should return 0.0 ± 0.1.
 ```rust
-let res:f64 = root_search::binary(&test_function::identity, (-1.0,2.0), 0.1)?;
-Ok(assert_eq!(0.0, (res*10.0).round()/10.0))
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::identity, -1.0, 2.0)?;
+let res:f64 = root_search::binary(&test_function::identity, bracket, 0.1, root_search::Budget::unlimited())?.result.root;
+assert_eq!(0.0, (res*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
 ```
 ---
 In this example, `root_search::binary` should return -2.9 ± 0.1.
 ```rust
-let res:f64 = root_search::binary(&test_function::trig, (-3.0,-2.0), 0.1)?;
-Ok(assert_eq!(-2.9, (res*10.0).round()/10.0))
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::trig, -3.0, -2.0)?;
+let res:f64 = root_search::binary(&test_function::trig, bracket, 0.1, root_search::Budget::unlimited())?.result.root;
+assert_eq!(-2.9, (res*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+---
+In this example, `root_search::binary` is run in single precision, and should return -2.9 ± 0.1.
+```rust
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::generic::trig, -3.0_f32, -2.0)?;
+let res:f32 = root_search::binary(&test_function::generic::trig, bracket, 0.1, root_search::Budget::unlimited())?.result.root;
+assert_eq!(-2.9, (res*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+---
+In this example, `root_search::binary` finds the root of ln(x) over [0, 2], where ln(0) is -inf.
+```rust
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&(|x: f64| x.ln()), 0.0, 2.0)?;
+let res:f64 = root_search::binary(&(|x: f64| x.ln()), bracket, 0.1, root_search::Budget::unlimited())?.result.root;
+assert_eq!(1.0, (res*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+---
+In this example, `root_search::binary` is allowed 5 evaluations of `trig`, and stops with an estimate correct to 0.1.
+```rust
+# use root_finding::*;
+let bracket = root_search::Bracket::new(&test_function::trig, -4.0, 0.0)?;
+let err = root_search::binary(&test_function::trig, bracket, 1e-12, root_search::Budget::new(100, Some(5))).unwrap_err();
+assert_eq!(root_search::TerminationReason::BudgetExhausted, err.reason);
+assert_eq!(-2.9, (err.estimate().unwrap()*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
 ```
 */
-pub fn binary(
-    func: &ContinuousFunction,
-    domain: (f64, f64),
-    trunc_err: f64,
-) -> Result<f64, Box<&'static str>> {
-    // Since we only care about signs, we get a tiny speedup by working with the signs of the function
-    // values, rather than the values themselves.
-    let func_sgn = compose_fn!(func => signum);
+pub fn binary<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<BracketedResult<T>, RootError<T>> {
+    trace_span!("binary");
+    let clock: Clock = Clock::start(&budget);
+    binary_with_history(
+        &CountedFunction::new(func),
+        bracket,
+        trunc_err,
+        T::zero(),
+        &budget,
+        &clock,
+    )
+    .map(|(bracket, result)| BracketedResult { result, bracket })
+}
 
-    let (mut start, mut end): (f64, f64) = domain;
-    let (mut start_val, end_val): (f64, f64) = (func_sgn(start), func_sgn(end));
+/**
+As `binary`, returning the final bracket and the result as a pair, for the solvers built on binary search.
 
-    if start_val == 0.0 {
-        return Ok(start);
-    }
-    if end_val == 0.0 {
-        return Ok(end);
+The search also stops, without narrowing the bracket, at a midpoint where `|F| < noise`.
+*/
+fn binary_with_history<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &CountedFunction<F>,
+    bracket: Bracket<T>,
+    trunc_err: T,
+    noise: T,
+    budget: &Budget,
+    clock: &Clock,
+) -> Result<(Bracket<T>, RootResult<T>), RootError<T>> {
+    let mut bracket: Bracket<T> = bracket;
+    let mut history: Vec<T> = Vec::new();
+    let mut residuals: Vec<T> = Vec::new();
+    let finish = |bracket: Bracket<T>, history: Vec<T>, residuals: Vec<T>| {
+        let root: T = bracket.midpoint();
+        Ok((
+            bracket,
+            RootResult {
+                root,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            },
+        ))
+    };
+
+    if bracket.f_lo.is_zero() {
+        return finish(Bracket::exact(bracket.lo), history, residuals);
     }
-    if start_val * end_val > 0.0 {
-        return Err(Box::new("Error: no sign change at endpoints!"));
+    if bracket.f_hi.is_zero() {
+        return finish(Bracket::exact(bracket.hi), history, residuals);
     }
 
-    let val: f64 = loop {
-        let midpoint: f64 = (end + start) / 2.0;
-        let test_val: f64 = func_sgn(midpoint);
+    while bracket.width() >= trunc_err {
+        let midpoint: T = bracket.midpoint();
+        // The endpoints are adjacent floats, so the bracket can't be narrowed any further.
+        if midpoint <= bracket.lo || midpoint >= bracket.hi {
+            break;
+        }
+        let reason: Option<TerminationReason> = if history.len() >= budget.max_iter {
+            Some(TerminationReason::MaxIterations)
+        } else if budget.exhausted(func.evaluations(), 1, clock) {
+            Some(TerminationReason::BudgetExhausted)
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            trace_info!(reason = ?reason, "budget spent");
+            history.push(midpoint);
+            return Err(RootError {
+                reason,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+
+        let test_val: T = func.call(midpoint);
+        history.push(midpoint);
+        residuals.push(test_val);
+        trace_debug!(
+            iteration = history.len(),
+            x = midpoint.to_f64(),
+            f = test_val.to_f64(),
+            width = bracket.width().to_f64()
+        );
 
-        if test_val == 0.0 || (end - start) < trunc_err {
-            break midpoint;
+        if !test_val.is_finite() {
+            trace_info!(x = midpoint.to_f64(), "function is not finite");
+            return Err(RootError {
+                reason: TerminationReason::NonFinite,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            });
         }
-        if test_val * start_val > 0.0 {
-            start = midpoint;
-            start_val = test_val;
+        if test_val.abs() < noise {
+            trace_info!(root = midpoint.to_f64(), "function within noise");
+            return finish(bracket, history, residuals);
+        }
+        if test_val.is_zero() {
+            trace_info!(root = midpoint.to_f64(), "found exact root");
+            return finish(Bracket::exact(midpoint), history, residuals);
+        }
+        if sgn(test_val) == sgn(bracket.f_lo) {
+            bracket.lo = midpoint;
+            bracket.f_lo = test_val;
         } else {
-            end = midpoint;
+            bracket.hi = midpoint;
+            bracket.f_hi = test_val;
         }
-    };
+    }
 
-    return Ok(val);
+    trace_info!(
+        lo = bracket.lo.to_f64(),
+        hi = bracket.hi.to_f64(),
+        iterations = history.len(),
+        "converged"
+    );
+    finish(bracket, history, residuals)
 }
 
 /**
-Return the fixed point of a function where one exists.
+Find an interval certainly containing a root of a continuous function, using binary search with interval arithmetic.
+
+Ordinary binary search trusts the computed sign of `F(m)`, which rounding error can get wrong when `F(m)` is tiny. Here
+`func` is evaluated over intervals, and the bracket is only narrowed when the sign of `F(m)` is certain. If the sign
+cannot be determined, `F(m)` is indistinguishable from 0 at working precision; the search stops there, and the
+returned interval, though wider than `trunc_err`, is as tight as can be verified.
+
+Only available with the `interval` feature.
 
 Parameters
 ----------
-* `func` : A continuous function with a fixed point, e.g. a contraction mapping.
-* `initial_val` : An initial guess for the location of the fixed point.
-* `trunc_err` : A float representing the acceptable truncation error for the search; e.g. `trunc_err=1` will result in finding the fixed point +-1.
-* `max_iter` : The maximum number of iterations the algorithm will use before it declares there is no fixed point. This is highly dependent on both the rate of convergence and the truncation error.
+* `func` : An interval extension of a continuous function, i.e. a function mapping each interval to an interval containing its image.
+* `domain` : The start and end points of the search interval.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
 
 Returns
 -------
-* `f64` : A float representing the fixed point.
-* `Vec<f64>` : A vector of all computed iterations.
+* `Interval` : An interval guaranteed to contain a root of the function.
 
 Errors
 ------
-* `Vec<f64>` : If function fails to converge in `max_iter`, returns the current sequence of computed iterations as an error.
+* If the function can't be verified to change sign at the endpoints
 
 Examples
 --------
-In this example, `root_search::fixed_point` should return 0.8 ± 0.1, the fixed point of the cos function.
+In this example, `root_search::binary_verified` should return an interval of width less than 1e-12 containing the root of `trig`.
 ```rust
-let (res, _seq) = root_search::fixed_point(&(|x:f64| -> f64 {x.cos()}), 2.5, 0.1, 10)?;
-Ok(assert_eq!(0.8, (res*10.0).round()/10.0))
+# use root_finding::*;
+# use root_finding::interval::Interval;
+let trig = |x: Interval| -> Interval { 2.0 * x - 3.0 * x.sin() + 5.0 };
+let res: Interval = root_search::binary_verified(&trig, (-3.0, -2.0), 1e-12)?;
+assert!(res.contains(-2.883236872558) && res.width() < 1e-12);
+# Ok::<(), &'static str>(())
 ```
 */
-pub fn fixed_point(
-    func: &ContinuousFunction,
-    initial_val: f64,
+#[cfg(feature = "interval")]
+pub fn binary_verified(
+    func: &dyn Fn(Interval) -> Interval,
+    domain: (f64, f64),
     trunc_err: f64,
-    max_iter: usize,
-) -> Result<(f64, Vec<f64>), Vec<f64>> {
-    let mut func_vals: Vec<f64> = Vec::with_capacity(max_iter);
-    let mut current_val: f64 = initial_val;
-    for _ in 1..max_iter {
-        func_vals.push(current_val);
-        let next_val: f64 = func(current_val);
-        if (next_val - current_val).abs() < trunc_err {
-            current_val = next_val;
-            return Ok((current_val, func_vals))
+) -> Result<Interval, &'static str> {
+    let (mut start, mut end): (f64, f64) = domain;
+    let start_val: Interval = func(Interval::point(start));
+    let end_val: Interval = func(Interval::point(end));
+
+    let start_negative: bool = if start_val.is_negative() && end_val.is_positive() {
+        true
+    } else if start_val.is_positive() && end_val.is_negative() {
+        false
+    } else {
+        return Err("Error: no verified sign change at endpoints!");
+    };
+
+    while (end - start).abs() >= trunc_err {
+        let midpoint: f64 = start + (end - start) / 2.0;
+        let test_val: Interval = func(Interval::point(midpoint));
+
+        if test_val.is_negative() == start_negative && !test_val.contains(0.0) {
+            start = midpoint;
+        } else if test_val.is_positive() == start_negative && !test_val.contains(0.0) {
+            end = midpoint;
+        } else {
+            break;
         }
-        current_val = next_val;
     }
-    func_vals.push(current_val);
-    Err(func_vals)
+
+    Ok(Interval::new(start, end))
+}
+
+/**
+Find a root of a continuous function using Chandrupatla's algorithm.
+
+Like Brent's method, Chandrupatla's algorithm keeps a bracket around the root, and takes a step of inverse quadratic
+interpolation through the last three points where that is safe, and of bisection where it isn't. Rather than Brent's
+several conditions, it interpolates exactly when the last three points satisfy `ϕ² < ξ` and `(1-ϕ)² < 1-ξ`, where `ξ`
+and `ϕ` locate the newest point relative to the other two in `x` and in `F(x)`; these hold precisely when the inverse
+quadratic through them is monotone. Each step is kept at least a tolerance inside the bracket, so the bracket always
+shrinks, and the algorithm converges superlinearly on smooth functions while never doing much worse than bisection.
+
+Parameters
+----------
+* `func` : A continuous function with a sign change over the given bracket.
+* `bracket` : The search interval. Note that `func` must be computable over the entire bracket, including end points.
+* `trunc_err` : A float representing the acceptable truncation error for the search; the root found is within `trunc_err` of a root, up to rounding.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time to spend.
+
+Returns
+-------
+* `RootResult<T>` : Whichever end of the final bracket has the smaller function value, with every point at which `func` was evaluated and the number of evaluations. The function values at the ends of the initial bracket are not recomputed, so aren't counted.
+
+Errors
+------
+* `RootError<T>` : If `func` is NaN or infinite at a point, returns the points computed, ending with that one. If the
+  budget runs out first, returns the points computed, followed by the best estimate of the root.
+
+Examples
+--------
+In this example, `root_search::chandrupatla` finds the root of `trig` to within 1e-12 in a fraction of the evaluations
+binary search needs.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Bracket, Budget};
+let bracket = Bracket::new(&test_function::trig, -4.0, 0.0)?;
+let res = root_search::chandrupatla(&test_function::trig, bracket, 1e-12, Budget::unlimited())?;
+assert!((res.root + 2.8832368725582835).abs() < 1e-12);
+assert!(res.evaluations < 15);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn chandrupatla<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    trace_span!("chandrupatla");
+    traced(iterate_chandrupatla(func, bracket, trunc_err, T::zero(), &budget).map(|res| res.result))
+}
+
+/// As `chandrupatla_noisy`, without recording the outcome.
+fn iterate_chandrupatla<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    trunc_err: T,
+    noise: T,
+    budget: &Budget,
+) -> Result<NoisyResult<T>, RootError<T>> {
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let clock: Clock = Clock::start(budget);
+    let two: T = T::one() + T::one();
+    let half: T = T::one() / two;
+    let mut history: Vec<T> = Vec::new();
+    let mut residuals: Vec<T> = Vec::new();
+
+    // `a` is the newest point, `b` the other end of the bracket, and `c` the point they replaced.
+    let (mut a, mut f_a): (T, T) = (bracket.hi, bracket.f_hi);
+    let (mut b, mut f_b): (T, T) = (bracket.lo, bracket.f_lo);
+    let (mut c, mut f_c): (T, T) = (a, f_a);
+    loop {
+        let (best, f_best): (T, T) = if f_a.abs() < f_b.abs() {
+            (a, f_a)
+        } else {
+            (b, f_b)
+        };
+        let tol: T = trunc_err / two + two * T::epsilon() * best.abs();
+        let t_lim: T = tol / (b - a).abs();
+        if f_best.is_zero() || t_lim > half {
+            return Ok(NoisyResult {
+                result: RootResult {
+                    root: best,
+                    history,
+                    residuals,
+                    evaluations: func.evaluations(),
+                },
+                accuracy: if f_best.is_zero() {
+                    T::zero()
+                } else {
+                    (b - a).abs()
+                },
+            });
+        }
+        // Before the first step `c = a`, so `ξ = ϕ = 1` and the first step is bisection.
+        let xi: T = (a - b) / (c - b);
+        let phi: T = (f_a - f_b) / (f_c - f_b);
+        let t: T = if phi * phi < xi && (T::one() - phi) * (T::one() - phi) < T::one() - xi {
+            f_a / (f_b - f_a) * f_c / (f_b - f_c)
+                + (c - a) / (b - a) * f_a / (f_c - f_a) * f_b / (f_c - f_b)
+        } else {
+            half
+        };
+        let t: T = t.max(t_lim).min(T::one() - t_lim);
+
+        let reason: Option<TerminationReason> = if history.len() >= budget.max_iter {
+            Some(TerminationReason::MaxIterations)
+        } else if budget.exhausted(func.evaluations(), 1, &clock) {
+            Some(TerminationReason::BudgetExhausted)
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            history.push(best);
+            return Err(RootError {
+                reason,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+
+        let x_t: T = a + t * (b - a);
+        let f_t: T = func.call(x_t);
+        history.push(x_t);
+        residuals.push(f_t);
+        trace_debug!(
+            iteration = history.len(),
+            x = x_t.to_f64(),
+            f = f_t.to_f64(),
+            width = (b - a).abs().to_f64()
+        );
+        if !f_t.is_finite() {
+            return Err(RootError {
+                reason: TerminationReason::NonFinite,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+        if f_t.abs() < noise {
+            trace_info!(root = x_t.to_f64(), "function within noise");
+            return Ok(NoisyResult {
+                result: RootResult {
+                    root: x_t,
+                    history,
+                    residuals,
+                    evaluations: func.evaluations(),
+                },
+                accuracy: noise_floor(a, b, f_a, f_b, noise),
+            });
+        }
+        if sgn(f_t) == sgn(f_a) {
+            c = a;
+            f_c = f_a;
+        } else {
+            c = b;
+            f_c = f_b;
+            b = a;
+            f_b = f_a;
+        }
+        a = x_t;
+        f_a = f_t;
+    }
+}
+
+/// The outcome of a successful search of a noisy function, by `binary_noisy` or `chandrupatla_noisy`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NoisyResult<T = f64> {
+    /// The root, with every point at which the function was evaluated and the number of evaluations.
+    pub result: RootResult<T>,
+    /// The accuracy to which the root is known. If the search stopped at a point where `|F| < noise`, this is an
+    /// estimate of `noise/|F'|`, the half-width of the interval around the root over which `F` can't be told apart
+    /// from zero, and no search can do better; otherwise it bounds the distance from the root found to a root.
+    pub accuracy: T,
+}
+
+/// An estimate of `noise/|F'|` from a bracket `[a, b]`, using the secant slope across it, but no more than its width.
+fn noise_floor<T: Float>(a: T, b: T, f_a: T, f_b: T, noise: T) -> T {
+    let width: T = (b - a).abs();
+    (noise * width / (f_b - f_a).abs()).min(width)
+}
+
+/// A root extrapolated from the iterates of a search, by `RootResult::extrapolate`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Extrapolation<T = f64> {
+    /// The extrapolated root.
+    pub root: T,
+    /// An a-posteriori estimate of the distance from `root` to the true root.
+    pub error: T,
+}
+
+/// The outcome of a successful search by `binary_extrapolated`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtrapolatedResult<T = f64> {
+    /// The midpoint of the final bracket, with every midpoint computed and the residual there.
+    pub result: RootResult<T>,
+    /// The root extrapolated from the midpoints, and its estimated error, which is no more than half the final bracket.
+    pub extrapolation: Extrapolation<T>,
+}
+
+/**
+Find a root of a continuous function whose values carry noise of up to `noise`, using binary search.
+
+Where `|F(x)|` is smaller than the noise, its computed sign is meaningless, and a plain search keeps bisecting on
+coin flips, returning digits that are no more than noise. Here such a midpoint is taken as the root, and the search
+stops there; the signs at every other point, and so the bracket, are still trustworthy. The accuracy reported is then
+how closely the root can be located at all given the noise, `noise/|F'|`, estimated from the final bracket.
+
+Parameters
+----------
+* `func` : A continuous function with a sign change over the given bracket, computed with an error of at most `noise`.
+* `bracket` : The search interval. The function values at its ends should exceed `noise` in size, or their signs, and so the bracket, can't be trusted.
+* `noise` : A bound on the error in each value of `func`.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time to spend.
+
+Returns
+-------
+* `NoisyResult<T>` : The midpoint of the final bracket, with every midpoint computed, and the accuracy to which it is known.
+
+Errors
+------
+* `RootError<T>` : As for `binary`.
+
+Examples
+--------
+In this example, `trig` is computed with noise of up to 1e-6, so can't be solved to 1e-12; `root_search::binary_noisy`
+stops once it reaches the noise, and reports that the root is known to about 1e-6.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Bracket, Budget};
+let noisy = |x: f64| test_function::trig(x) + 1e-6 * (1e6 * x).sin();
+let bracket = Bracket::new(&noisy, -4.0, 0.0)?;
+let res = root_search::binary_noisy(&noisy, bracket, 1e-6, 1e-12, Budget::unlimited())?;
+assert!((res.result.root + 2.8832368725582835).abs() < 1e-6);
+assert!(1e-7 < res.accuracy && res.accuracy < 1e-6);
+assert!(res.result.history.len() < 30);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn binary_noisy<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    noise: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<NoisyResult<T>, RootError<T>> {
+    trace_span!("binary_noisy");
+    let clock: Clock = Clock::start(&budget);
+    let (bracket, result): (Bracket<T>, RootResult<T>) = binary_with_history(
+        &CountedFunction::new(func),
+        bracket,
+        trunc_err,
+        noise,
+        &budget,
+        &clock,
+    )?;
+    // Only a search stopped by the noise leaves a bracket as wide as `trunc_err`.
+    let accuracy: T = if bracket.width() >= trunc_err {
+        noise_floor(bracket.lo, bracket.hi, bracket.f_lo, bracket.f_hi, noise)
+    } else {
+        bracket.width() / (T::one() + T::one())
+    };
+    Ok(NoisyResult { result, accuracy })
+}
+
+/**
+Find a root of a continuous function whose values carry noise of up to `noise`, using Chandrupatla's algorithm.
+
+As `binary_noisy`, the search stops at the first point where `|F| < noise`, reporting the accuracy `noise/|F'|`
+achievable there. Otherwise, it is as `chandrupatla`.
+
+Parameters
+----------
+* `func` : A continuous function with a sign change over the given bracket, computed with an error of at most `noise`.
+* `bracket` : The search interval. The function values at its ends should exceed `noise` in size.
+* `noise` : A bound on the error in each value of `func`.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time to spend.
+
+Returns
+-------
+* `NoisyResult<T>` : The root, with every point at which `func` was evaluated, and the accuracy to which it is known.
+
+Errors
+------
+* `RootError<T>` : As for `chandrupatla`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Bracket, Budget};
+let noisy = |x: f64| test_function::trig(x) + 1e-6 * (1e6 * x).sin();
+let bracket = Bracket::new(&noisy, -4.0, 0.0)?;
+let res = root_search::chandrupatla_noisy(&noisy, bracket, 1e-6, 1e-12, Budget::unlimited())?;
+assert!((res.result.root + 2.8832368725582835).abs() < 1e-6);
+assert!(1e-7 < res.accuracy && res.accuracy < 1e-6);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn chandrupatla_noisy<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    noise: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<NoisyResult<T>, RootError<T>> {
+    trace_span!("chandrupatla_noisy");
+    let mut accuracy: T = T::zero();
+    let res: Result<RootResult<T>, RootError<T>> =
+        iterate_chandrupatla(func, bracket, trunc_err, noise, &budget).map(|res| {
+            accuracy = res.accuracy;
+            res.result
+        });
+    traced(res).map(|result| NoisyResult { result, accuracy })
+}
+
+/**
+Find a root of a continuous function using binary search, and extrapolate from the midpoints to a better estimate.
+
+The error of a midpoint of bisection doesn't fall geometrically, but jumps about with the binary digits of the root,
+so Aitken's method on the midpoints alone gains nothing. Their residuals, however, recorded at no extra cost, locate
+the root much more closely: `RootResult::extrapolate` extrapolates from the last three midpoints to where the residual
+is zero, with an a-posteriori estimate of the error. Where this estimate is smaller than half the final bracket, and
+the extrapolated root lies in the bracket, it is taken in place of the midpoint; otherwise, e.g. when the search
+stopped in fewer than three steps, the extrapolation is just the midpoint, with half the width of the bracket as its
+error, which is certain.
+
+Parameters
+----------
+* `func` : A continuous function with a sign change over the given bracket.
+* `bracket` : The search interval.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time to spend.
+
+Returns
+-------
+* `ExtrapolatedResult<T>` : The midpoint of the final bracket, with every midpoint computed, and the extrapolated root.
+
+Errors
+------
+* `RootError<T>` : As for `binary`.
+
+Examples
+--------
+In this example, bisection on `trig` to a bracket of width 1e-3 locates the root to about 1e-4, while the root
+extrapolated from the midpoints is accurate to 1e-9.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Bracket, Budget};
+let bracket = Bracket::new(&test_function::trig, -4.0, 0.0)?;
+let res = root_search::binary_extrapolated(&test_function::trig, bracket, 1e-3, Budget::unlimited())?;
+assert!((res.result.root + 2.8832368725582835).abs() > 1e-5);
+assert!((res.extrapolation.root + 2.8832368725582835).abs() < 1e-9);
+assert!(res.extrapolation.error < 1e-5);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn binary_extrapolated<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    bracket: Bracket<T>,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<ExtrapolatedResult<T>, RootError<T>> {
+    trace_span!("binary_extrapolated");
+    let clock: Clock = Clock::start(&budget);
+    let (bracket, result): (Bracket<T>, RootResult<T>) = binary_with_history(
+        &CountedFunction::new(func),
+        bracket,
+        trunc_err,
+        T::zero(),
+        &budget,
+        &clock,
+    )?;
+    let midpoint: Extrapolation<T> = Extrapolation {
+        root: result.root,
+        error: bracket.width() / (T::one() + T::one()),
+    };
+    let extrapolation: Extrapolation<T> = match result.extrapolate() {
+        Some(extrapolation)
+            if bracket.lo <= extrapolation.root
+                && extrapolation.root <= bracket.hi
+                && extrapolation.error < midpoint.error =>
+        {
+            extrapolation
+        }
+        _ => midpoint,
+    };
+    trace_info!(
+        root = extrapolation.root.to_f64(),
+        error = extrapolation.error.to_f64(),
+        "extrapolated"
+    );
+    Ok(ExtrapolatedResult {
+        result,
+        extrapolation,
+    })
+}
+
+/**
+Return the fixed point of a function where one exists.
+
+Parameters
+----------
+* `func` : A continuous function with a fixed point, e.g. a contraction mapping.
+* `initial_val` : An initial guess for the location of the fixed point.
+* `trunc_err` : A float representing the acceptable truncation error for the search; e.g. `trunc_err=1` will result in finding the fixed point +-1.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time the algorithm will use before it declares there is no fixed point.
+
+Returns
+-------
+* `RootResult<T>` : The fixed point, with every computed iterate (excluding the fixed point itself) and the number of evaluations of `func`.
+
+Errors
+------
+* `RootError<T>` : If the function fails to converge within the budget, returns the current sequence of computed iterations as an error.
+  The search also stops early, with reason `TerminationReason::NonFinite`, if `func` is NaN or infinite at the last
+  iterate, with reason `TerminationReason::Diverged`, if both the step and the size of the iterate
+  have grown for 10 consecutive iterations, or with reason `TerminationReason::Cycling`, if an iterate repeats one
+  computed earlier. For cycle detection, iterates are compared after rounding to a thousandth of `trunc_err`, so a
+  sequence converging slowly enough may be mistaken for a cycle.
+
+Examples
+--------
+In this example, `root_search::fixed_point` should return 0.8 ± 0.1, the fixed point of the cos function.
+```rust
+# use root_finding::*;
+let res = root_search::fixed_point(&(|x:f64| -> f64 {x.cos()}), 2.5, 0.1, root_search::Budget::new(10, None))?;
+assert_eq!(0.8, (res.root*10.0).round()/10.0);
+# Ok::<(), root_search::RootError>(())
+```
+
+In this example, Newton-Raphson on `x^3 - 2x + 2` from 0 alternates between 0 and 1, and is stopped once the cycle is
+detected.
+```rust
+# use root_finding::*;
+let func = |x: f64| x.powi(3) - 2.0 * x + 2.0;
+let deriv = |x: f64| 3.0 * x.powi(2) - 2.0;
+let newton = functional::x_minus(functional::newton_raphson(&func, &deriv));
+let err = root_search::fixed_point(&newton, 0.0, 1e-10, root_search::Budget::new(100, None)).unwrap_err();
+assert_eq!(root_search::TerminationReason::Cycling { period: 2 }, err.reason);
+```
+*/
+pub fn fixed_point<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    initial_val: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    trace_span!("fixed_point");
+    traced(iterate_fixed_point(func, initial_val, trunc_err, &budget))
+}
+
+/// As `fixed_point`, without recording the outcome.
+fn iterate_fixed_point<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    initial_val: T,
+    trunc_err: T,
+    budget: &Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let clock: Clock = Clock::start(budget);
+    let resolution: T = trunc_err * T::from(CYCLE_RESOLUTION).unwrap();
+    let mut cycles: CycleDetector<T> = CycleDetector::new();
+    let mut growing_steps: usize = 0;
+    let mut last_step: T = T::infinity();
+    let mut func_vals: Vec<T> = Vec::new();
+    let mut residuals: Vec<T> = Vec::new();
+    let mut current_val: T = initial_val;
+    for _ in 0..budget.max_iter {
+        func_vals.push(current_val);
+        let rounded: T = (current_val / resolution).round();
+        let reason: Option<TerminationReason> = if growing_steps >= DIVERGENCE_STEPS {
+            Some(TerminationReason::Diverged)
+        } else if budget.exhausted(func.evaluations(), 1, &clock) {
+            Some(TerminationReason::BudgetExhausted)
+        } else if rounded.is_finite() {
+            cycles
+                .observe(rounded)
+                .map(|period| TerminationReason::Cycling { period })
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(RootError {
+                reason,
+                history: func_vals,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+
+        let next_val: T = func.call(current_val);
+        residuals.push(next_val - current_val);
+        trace_debug!(
+            iteration = func_vals.len(),
+            x = current_val.to_f64(),
+            next = next_val.to_f64()
+        );
+        if !next_val.is_finite() {
+            return Err(RootError {
+                reason: TerminationReason::NonFinite,
+                history: func_vals,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+        let step: T = (next_val - current_val).abs();
+        if step < trunc_err {
+            return Ok(RootResult {
+                root: next_val,
+                history: func_vals,
+                residuals,
+                evaluations: func.evaluations(),
+            });
+        }
+        if step > last_step && next_val.abs() > current_val.abs() {
+            growing_steps += 1;
+        } else {
+            growing_steps = 0;
+        }
+        last_step = step;
+        current_val = next_val;
+    }
+    func_vals.push(current_val);
+    Err(RootError {
+        reason: TerminationReason::MaxIterations,
+        history: func_vals,
+        residuals,
+        evaluations: func.evaluations(),
+    })
+}
+
+/// How `relaxed_fixed_point` chooses the relaxation parameter `ω` in `x -> (1-ω)x + ωg(x)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Relaxation<T = f64> {
+    /// The same `ω` at every step. `ω = 1` is plain fixed point iteration, and `0 < ω < 1` damps it.
+    Fixed(T),
+    /// Wegstein's method: `ω = 1/(1-a)`, where `a` is the slope of `g` estimated from the last two iterates. The first
+    /// step, and any step at which the slope can't be estimated, is plain fixed point iteration.
+    Wegstein,
+}
+
+/**
+Return the fixed point of a function using relaxed fixed point iteration, `x -> (1-ω)x + ωg(x)`.
+
+Plain fixed point iteration converges only when `|g'| < 1` near the fixed point, and oscillates when `g' < 0`. A fixed
+`ω` can turn a non-contractive map into a contraction: near the fixed point, the relaxed map has slope `1 - ω + ωg'`,
+so for `g' < 1` any `0 < ω < 2/(1-g')` converges. Wegstein's method instead chooses `ω` at each step to make the relaxed
+slope 0, using the secant slope of `g` through the last two iterates, so converges superlinearly whether or not `g` is
+a contraction.
+
+Parameters
+----------
+* `func` : A continuous function with a fixed point.
+* `relaxation` : How to choose the relaxation parameter.
+* `initial_val` : An initial guess for the location of the fixed point.
+* `trunc_err` : A float representing the acceptable truncation error for the search. Note that the search stops when
+  the relaxed step is smaller than `trunc_err`, so a small fixed `ω` calls for a correspondingly smaller `trunc_err`.
+* `budget` : The maximum number of iterations, evaluations of `func`, and time the algorithm will use before it declares there is no fixed point.
+
+Returns
+-------
+* `RootResult<T>` : The fixed point, with every computed iterate (excluding the fixed point itself) and the number of evaluations of `func`.
+
+Errors
+------
+* `RootError<T>` : As for `fixed_point`.
+
+Examples
+--------
+In this example, plain fixed point iteration of `frac` with `k=0` fails to find the root of `trig` from -2, since
+`|g'| > 1` there, but Wegstein's method converges to it.
+```rust
+# use root_finding::*;
+use root_finding::root_search::Relaxation;
+let g = functional::x_minus(functional::frac(&test_function::trig, &0.0));
+assert!(root_search::fixed_point(&g, -2.0, 1e-10, root_search::Budget::new(100, None)).is_err());
+let res = root_search::relaxed_fixed_point(&g, Relaxation::Wegstein, -2.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(-2.9, (res.root*10.0).round()/10.0);
+# Ok::<(), root_search::RootError>(())
+```
+---
+In this example, damping with `ω = 0.5` makes the same map a contraction.
+```rust
+# use root_finding::*;
+use root_finding::root_search::Relaxation;
+let g = functional::x_minus(functional::frac(&test_function::trig, &0.0));
+let res = root_search::relaxed_fixed_point(&g, Relaxation::Fixed(0.5), -2.0, 1e-10, root_search::Budget::new(100, None))?;
+assert_eq!(-2.9, (res.root*10.0).round()/10.0);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn relaxed_fixed_point<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    relaxation: Relaxation<T>,
+    initial_val: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    trace_span!("relaxed_fixed_point");
+    // The last point at which `func` was evaluated, and its value there, for Wegstein's slope estimate.
+    let last: Cell<Option<(T, T)>> = Cell::new(None);
+    let relaxed = |x: T| -> T {
+        let g_x: T = func.eval(x);
+        let omega: T = match relaxation {
+            Relaxation::Fixed(omega) => omega,
+            Relaxation::Wegstein => {
+                let omega: Option<T> = last.get().and_then(|(x_prev, g_prev)| {
+                    let slope: T = (g_x - g_prev) / (x - x_prev);
+                    let omega: T = T::one() / (T::one() - slope);
+                    if omega.is_finite() {
+                        Some(omega)
+                    } else {
+                        None
+                    }
+                });
+                last.set(Some((x, g_x)));
+                omega.unwrap_or(T::one())
+            }
+        };
+        (T::one() - omega) * x + omega * g_x
+    };
+    fixed_point(&relaxed, initial_val, trunc_err, budget)
+}
+
+/// The globally convergent method with which `hybrid_newton` approaches the root before switching to Newton-Raphson.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(deserialize = "T: Float + Deserialize<'de>"))
+)]
+pub enum GlobalPhase<T = f64> {
+    /// Binary search over the bracket, until it is narrower than the switching tolerance.
+    Bisection(Bracket<T>),
+    /// Damped Newton-Raphson `x -> x - ωF(x)/F'(x)` with `0 < ω < 1` from `initial_val`, until a step is smaller
+    /// than the switching tolerance.
+    Damped { initial_val: T, omega: T },
+}
+
+/// The outcome of a successful `hybrid_newton` search.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HybridResult<T = f64> {
+    /// The root, with the iterates of both phases in order, and the evaluations of both.
+    pub result: RootResult<T>,
+    /// The index into `result.history` of the first Newton-Raphson iterate.
+    pub switch: usize,
+}
+
+/**
+Find a root with a globally convergent method, switching to Newton-Raphson once close enough to it.
+
+Newton-Raphson converges quadratically, but only from close to a simple root; from further away it can diverge or
+cycle. Binary search and damped Newton-Raphson converge from much further away, but only linearly. Running the robust
+method until it is within `switch_tol` of the root, and polishing with Newton-Raphson from there, gets the best of both.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `deriv` : The derivative of `func`.
+* `phase` : The globally convergent method to start with, and where to start it.
+* `switch_tol` : The bracket width or step size below which to switch to Newton-Raphson.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
+* `budget` : The maximum number of iterations of each phase, and evaluations of `func` and `deriv` together and time over both phases.
+
+Returns
+-------
+* `HybridResult<T>` : The root, with every computed iterate and the index of the first Newton-Raphson iterate.
+
+Errors
+------
+* `RootError<T>` : If either phase fails, as for `binary` and `fixed_point`, with the iterates of both phases.
+
+Examples
+--------
+In this example, `root_search::hybrid_newton` bisects the bracket [-4, 0] of `trig` down to width 0.1, then switches to
+Newton-Raphson, which converges in a few more iterates.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Bracket, GlobalPhase};
+let bracket = Bracket::new(&test_function::trig, -4.0, 0.0).unwrap();
+let res = root_search::hybrid_newton(&test_function::trig, &test_func_derivative::trig, GlobalPhase::Bisection(bracket), 0.1, 1e-12, root_search::Budget::new(100, None))?;
+assert_eq!(6, res.switch);
+assert!((res.result.root + 2.8832368725582835).abs() < 1e-12);
+# Ok::<(), root_search::RootError>(())
+```
+---
+In this example, damped Newton-Raphson is run from -2, and every evaluation of `trig` and its derivative is counted,
+both in the result and against the budget.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{Budget, CountedFunction, GlobalPhase, TerminationReason};
+let (func, deriv) = (CountedFunction::new(&test_function::trig), CountedFunction::new(&test_func_derivative::trig));
+let (f, df) = (|x: f64| func.call(x), |x: f64| deriv.call(x));
+let phase = GlobalPhase::Damped { initial_val: -2.0, omega: 0.5 };
+let res = root_search::hybrid_newton(&f, &df, phase, 0.1, 1e-12, Budget::new(100, None))?;
+assert_eq!(func.evaluations() + deriv.evaluations(), res.result.evaluations);
+
+let err = root_search::hybrid_newton(&f, &df, phase, 0.1, 1e-12, Budget::new(100, Some(10))).unwrap_err();
+assert_eq!(TerminationReason::BudgetExhausted, err.reason);
+assert!(err.evaluations <= 10);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn hybrid_newton<T: Float, F: ObjectiveFn<T> + ?Sized, D: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    deriv: &D,
+    phase: GlobalPhase<T>,
+    switch_tol: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<HybridResult<T>, RootError<T>> {
+    trace_span!("hybrid_newton");
+    let clock: Clock = Clock::start(&budget);
+    let (func, deriv): (CountedFunction<F>, CountedFunction<D>) =
+        (CountedFunction::new(func), CountedFunction::new(deriv));
+    let evaluations = || func.evaluations() + deriv.evaluations();
+    // The Newton-Raphson map, recording `F(x)` at every point it is applied to, since `fixed_point` only sees the map.
+    let func_vals: RefCell<Vec<T>> = RefCell::new(Vec::new());
+    let newton = |x: T| -> T {
+        let func_val: T = func.call(x);
+        func_vals.borrow_mut().push(func_val);
+        let deriv_val: T = deriv.call(x);
+        if deriv_val.is_zero() {
+            x - T::one()
+        } else {
+            x - func_val / deriv_val
+        }
+    };
+    // `fixed_point` counts each application of `newton` as one evaluation, but each evaluates both `F` and `F'`, so it
+    // is given half of what is left of the evaluation budget.
+    let newton_budget = || -> Budget {
+        let remaining: Budget = budget.remaining(evaluations(), &clock);
+        Budget {
+            max_evals: remaining.max_evals.map(|max_evals| max_evals / 2),
+            ..remaining
+        }
+    };
+    let (start, mut history, mut residuals): (T, Vec<T>, Vec<T>) = match phase {
+        GlobalPhase::Bisection(bracket) => {
+            let (_, res) =
+                binary_with_history(&func, bracket, switch_tol, T::zero(), &budget, &clock)?;
+            (res.root, res.history, res.residuals)
+        }
+        GlobalPhase::Damped { initial_val, omega } => {
+            let res: RootResult<T> = relaxed_fixed_point(
+                &newton,
+                Relaxation::Fixed(omega),
+                initial_val,
+                switch_tol,
+                newton_budget(),
+            )
+            .map_err(|err| RootError {
+                residuals: func_vals.take(),
+                evaluations: evaluations(),
+                ..err
+            })?;
+            (res.root, res.history, func_vals.take())
+        }
+    };
+
+    let switch: usize = history.len();
+    trace_info!(
+        switch,
+        x = start.to_f64(),
+        evaluations = evaluations(),
+        "switching to Newton-Raphson"
+    );
+    match fixed_point(&newton, start, trunc_err, newton_budget()) {
+        Ok(res) => {
+            history.extend(res.history);
+            residuals.extend(func_vals.take());
+            Ok(HybridResult {
+                result: RootResult {
+                    root: res.root,
+                    history,
+                    residuals,
+                    evaluations: evaluations(),
+                },
+                switch,
+            })
+        }
+        Err(err) => {
+            history.extend(err.history);
+            residuals.extend(func_vals.take());
+            Err(RootError {
+                reason: err.reason,
+                history,
+                residuals,
+                evaluations: evaluations(),
+            })
+        }
+    }
+}
+
+/**
+Find a root of known or unknown multiplicity using the modified Newton-Raphson iteration `x -> x - mF(x)/F'(x)`.
+
+At a root of multiplicity `m`, plain Newton-Raphson converges only linearly, with error ratio `(m-1)/m`. Scaling the
+step by `m` restores quadratic convergence. If `m` is not known, plain Newton-Raphson steps are taken until
+`estimate_multiplicity` gives the same (rounded) multiplicity twice in a row, after which that multiplicity is used.
+
+Parameters
+----------
+* `func` : A differentiable function.
+* `deriv` : The derivative of `func`.
+* `m` : The multiplicity of the root sought, or `None` to detect it.
+* `initial_val` : An initial guess for the location of the root.
+* `trunc_err` : A float representing the acceptable truncation error for the search.
+* `budget` : The maximum number of iterations, evaluations of `func` and `deriv` together, and time the algorithm will use before it declares there is no root. Each iteration uses two evaluations.
+
+Returns
+-------
+* `RootResult<T>` : The root, with every computed iterate (excluding the root itself) and the number of evaluations of `func` and `deriv`.
+
+Errors
+------
+* `RootError<T>` : If the function fails to converge within the budget, returns the current sequence of computed iterations as an error.
+  The search also stops early, with reason `TerminationReason::NonFinite`, if `func` or `deriv` is NaN or infinite at
+  the last iterate.
+
+Examples
+--------
+In this example, `root_search::newton_multiplicity` should find the double root of `polynom` at 4.0 ± 0.1, detecting its multiplicity.
+```rust
+# use root_finding::*;
+let res = root_search::newton_multiplicity(&test_function::polynom, &test_func_derivative::polynom, None, 5.0, 1e-6, root_search::Budget::new(100, None))?;
+assert_eq!(4.0, (res.root*10.0).round()/10.0);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn newton_multiplicity<T: Float, F: ObjectiveFn<T> + ?Sized, D: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    deriv: &D,
+    m: Option<T>,
+    initial_val: T,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    trace_span!("newton_multiplicity");
+    traced(iterate_newton_multiplicity(
+        func,
+        deriv,
+        m,
+        initial_val,
+        trunc_err,
+        &budget,
+    ))
+}
+
+/// As `newton_multiplicity`, without recording the outcome.
+fn iterate_newton_multiplicity<T: Float, F: ObjectiveFn<T> + ?Sized, D: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    deriv: &D,
+    m: Option<T>,
+    initial_val: T,
+    trunc_err: T,
+    budget: &Budget,
+) -> Result<RootResult<T>, RootError<T>> {
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let deriv: CountedFunction<D> = CountedFunction::new(deriv);
+    let clock: Clock = Clock::start(budget);
+    let evaluations = || -> usize { func.evaluations() + deriv.evaluations() };
+    let mut multiplicity: T = m.unwrap_or(T::one());
+    let mut detected: bool = m.is_some();
+    let mut last_estimate: Option<T> = None;
+
+    let mut func_vals: Vec<T> = Vec::new();
+    let mut residuals: Vec<T> = Vec::new();
+    let mut current_val: T = initial_val;
+    for _ in 0..budget.max_iter {
+        func_vals.push(current_val);
+        if !detected {
+            let estimate: Option<T> =
+                estimate_multiplicity(&func_vals).map(|m| m.round().max(T::one()));
+            if let (Some(estimate), Some(last_estimate)) = (estimate, last_estimate) {
+                if estimate == last_estimate {
+                    multiplicity = estimate;
+                    detected = true;
+                    trace_info!(
+                        multiplicity = multiplicity.to_f64(),
+                        "multiplicity detected"
+                    );
+                }
+            }
+            last_estimate = estimate;
+        }
+
+        if budget.exhausted(evaluations(), 2, &clock) {
+            return Err(RootError {
+                reason: TerminationReason::BudgetExhausted,
+                history: func_vals,
+                residuals,
+                evaluations: evaluations(),
+            });
+        }
+        let func_val: T = func.call(current_val);
+        let deriv_val: T = deriv.call(current_val);
+        residuals.push(func_val);
+        let next_val: T = if deriv_val.is_zero() {
+            current_val - T::one()
+        } else {
+            current_val - multiplicity * func_val / deriv_val
+        };
+        trace_debug!(
+            iteration = func_vals.len(),
+            x = current_val.to_f64(),
+            next = next_val.to_f64(),
+            multiplicity = multiplicity.to_f64()
+        );
+        if !func_val.is_finite() || !deriv_val.is_finite() || !next_val.is_finite() {
+            return Err(RootError {
+                reason: TerminationReason::NonFinite,
+                history: func_vals,
+                residuals,
+                evaluations: evaluations(),
+            });
+        }
+        if (next_val - current_val).abs() < trunc_err {
+            return Ok(RootResult {
+                root: next_val,
+                history: func_vals,
+                residuals,
+                evaluations: evaluations(),
+            });
+        }
+        current_val = next_val;
+    }
+    func_vals.push(current_val);
+    Err(RootError {
+        reason: TerminationReason::MaxIterations,
+        history: func_vals,
+        residuals,
+        evaluations: evaluations(),
+    })
+}
+
+/**
+Find a root of a complex function using Newton-Raphson iteration `z -> z - F(z)/F'(z)` in the complex plane.
+
+A real function such as `x^2 + 1` may have only complex roots, which no real method can find. Started from a point off
+the real axis, complex Newton-Raphson converges to them, quadratically near a simple root, just as on the real line.
+The tolerance is on the modulus of the step, so the search stops once successive iterates are within `trunc_err` of
+each other in the complex plane, and the residuals recorded are the complex values `F(z_n)`.
+
+Parameters
+----------
+* `func` : An analytic function.
+* `deriv` : The derivative of `func`.
+* `initial_val` : An initial guess for the location of the root. From a real guess, the iterates of a real function stay real.
+* `trunc_err` : A float representing the acceptable truncation error for the search, as a distance in the complex plane.
+* `budget` : The maximum number of iterations, evaluations of `func` and `deriv` together, and time the algorithm will use before it declares there is no root. Each iteration uses two evaluations.
+
+Returns
+-------
+* `RootResult<Complex<T>>` : The root, with every computed iterate (excluding the root itself), the residual at each, and the number of evaluations of `func` and `deriv`.
+
+Errors
+------
+* `RootError<Complex<T>>` : If the function fails to converge within the budget, returns the current sequence of computed iterations as an error.
+  The search also stops early, with reason `TerminationReason::NonFinite`, if `func` or `deriv` is NaN or infinite at
+  the last iterate.
+
+Examples
+--------
+In this example, `root_search::newton_complex` finds the root `i` of `z^2 + 1`, which has no real roots.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use root_finding::*;
+use num::Complex;
+let func = functional::shift(|z: Complex<f64>| z * z, Complex::new(1.0, 0.0));
+let deriv = |z: Complex<f64>| 2.0 * z;
+let res = root_search::newton_complex(&func, &deriv, Complex::new(1.0, 1.0), 1e-12, root_search::Budget::new(100, None))?;
+assert!((res.root - Complex::i()).norm() < 1e-12);
+assert_eq!(res.history.len(), res.residuals.len());
+# Ok::<(), root_search::RootError<Complex<f64>>>(())
+```
+---
+In this example, `root_search::newton_complex` finds the complex root `(-1 + i√3)/2` of the polynomial `x^2 + x + 1`.
+```rust
+# extern crate num;
+# extern crate root_finding;
+# use root_finding::*;
+use num::Complex;
+use root_finding::polynomial::Polynomial;
+let poly = Polynomial::new(vec![1.0, 1.0, 1.0]);
+let deriv = poly.derivative();
+let res = root_search::newton_complex(&|z| poly.eval_complex(z), &|z| deriv.eval_complex(z), Complex::new(0.0, 1.0), 1e-12, root_search::Budget::new(100, None))?;
+assert!((res.root - Complex::new(-0.5, 3.0_f64.sqrt() / 2.0)).norm() < 1e-12);
+# Ok::<(), root_search::RootError<Complex<f64>>>(())
+```
+*/
+pub fn newton_complex<
+    T: Float,
+    F: ObjectiveFn<Complex<T>> + ?Sized,
+    D: ObjectiveFn<Complex<T>> + ?Sized,
+>(
+    func: &F,
+    deriv: &D,
+    initial_val: Complex<T>,
+    trunc_err: T,
+    budget: Budget,
+) -> Result<RootResult<Complex<T>>, RootError<Complex<T>>> {
+    trace_span!("newton_complex");
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let deriv: CountedFunction<D> = CountedFunction::new(deriv);
+    let clock: Clock = Clock::start(&budget);
+    let evaluations = || -> usize { func.evaluations() + deriv.evaluations() };
+
+    let mut func_vals: Vec<Complex<T>> = Vec::new();
+    let mut residuals: Vec<Complex<T>> = Vec::new();
+    let mut current_val: Complex<T> = initial_val;
+    let (reason, history, residuals): (TerminationReason, Vec<Complex<T>>, Vec<Complex<T>>) = loop {
+        func_vals.push(current_val);
+        if residuals.len() >= budget.max_iter {
+            break (TerminationReason::MaxIterations, func_vals, residuals);
+        }
+        if budget.exhausted(evaluations(), 2, &clock) {
+            break (TerminationReason::BudgetExhausted, func_vals, residuals);
+        }
+        let func_val: Complex<T> = func.call(current_val);
+        let deriv_val: Complex<T> = deriv.call(current_val);
+        residuals.push(func_val);
+        let next_val: Complex<T> = if deriv_val.re.is_zero() && deriv_val.im.is_zero() {
+            current_val - Complex::new(T::one(), T::zero())
+        } else {
+            current_val - func_val / deriv_val
+        };
+        trace_debug!(
+            iteration = func_vals.len(),
+            re = current_val.re.to_f64(),
+            im = current_val.im.to_f64(),
+            residual = func_val.norm().to_f64()
+        );
+        if ![func_val, deriv_val, next_val]
+            .iter()
+            .all(|z| z.re.is_finite() && z.im.is_finite())
+        {
+            break (TerminationReason::NonFinite, func_vals, residuals);
+        }
+        if (next_val - current_val).norm() < trunc_err {
+            trace_info!(
+                re = next_val.re.to_f64(),
+                im = next_val.im.to_f64(),
+                iterations = func_vals.len(),
+                evaluations = evaluations(),
+                "converged"
+            );
+            return Ok(RootResult {
+                root: next_val,
+                history: func_vals,
+                residuals,
+                evaluations: evaluations(),
+            });
+        }
+        current_val = next_val;
+    };
+    trace_info!(reason = ?reason, iterations = history.len(), "failed");
+    Err(RootError {
+        reason,
+        history,
+        residuals,
+        evaluations: evaluations(),
+    })
+}
+
+/**
+Estimates the multiplicity of a root from the last three iterates of plain Newton-Raphson iteration.
+
+Near a root of multiplicity `m`, the steps of Newton-Raphson shrink by a constant ratio `r = (m-1)/m`, so
+`m = 1/(1-r)`. The estimate is only meaningful once the iterates are close to the root.
+
+Parameters
+----------
+* `history` : The iterates of plain Newton-Raphson iteration.
+
+Returns
+-------
+* `Option<T>` : The estimated multiplicity, or `None` if there are fewer than three iterates or the steps are not shrinking.
+
+Examples
+--------
+In this example, `root_search::estimate_multiplicity` should detect that `polynom` has a double root at 4.
+```rust
+# use root_finding::*;
+let newton = functional::x_minus(functional::newton_raphson(&test_function::polynom, &test_func_derivative::polynom));
+let seq: Vec<f64> = root_search::fixed_point(&newton, 5.0, 1e-3, root_search::Budget::new(100, None))?.history;
+assert_eq!(Some(2.0), root_search::estimate_multiplicity(&seq).map(|m| m.round()));
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn estimate_multiplicity<T: Float>(history: &[T]) -> Option<T> {
+    if history.len() < 3 {
+        return None;
+    }
+    let n: usize = history.len();
+    let last_step: T = (history[n - 1] - history[n - 2]).abs();
+    let prev_step: T = (history[n - 2] - history[n - 3]).abs();
+    let ratio: T = last_step / prev_step;
+    if ratio.is_nan() || ratio >= T::one() {
+        return None;
+    }
+    Some(T::one() / (T::one() - ratio))
+}
+
+/**
+Find every root of a continuous function in an interval.
+
+The domain is split into `n_subdivisions` equal sub-intervals. Each sub-interval over which the function changes sign
+is searched with `binary`, and each subdivision point at which the function vanishes is a root in its own right. Roots
+are only found if they are separated by a sign change, so a double root (where the function touches zero without
+crossing) or a pair of roots within the same sub-interval will be missed; more subdivisions make the latter less likely.
+The subdivision points are evaluated in a single call to `BatchedFunction::eval_many`.
+
+A sign change need not mean a root: `tan` changes sign across its poles. A search is taken to have converged on a pole,
+and its result is dropped, if `|func|` at both ends of its final bracket is larger than at both ends of the
+sub-interval it started from. A search which stops because `func` is NaN or infinite at a midpoint is likewise dropped,
+and the scan goes on to the next sub-interval.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `domain` : The start and end points of the search interval. Subdivision points at which `func` is NaN are skipped.
+* `n_subdivisions` : The number of sub-intervals to scan for sign changes.
+* `trunc_err` : A float representing the acceptable truncation error for each root. If it is smaller than the spacing
+  of floats near a root, the search for that root stops at adjacent floats.
+
+Returns
+-------
+* `Vec<RootResult<T>>` : The roots found, in increasing order, each with the midpoints computed by the binary search and
+  the evaluations it made. The evaluations made in scanning the subdivision points are not included.
+
+Examples
+--------
+In this example, `root_search::find_all_roots` should find the three roots of sin(x) in [-4,4].
+```rust
+# use root_finding::*;
+let res = root_search::find_all_roots(&(|x:f64| -> f64 {x.sin()}), (-4.0,4.0), 100, 1e-6);
+let roots: Vec<f64> = res.iter().map(|r| (r.root*10.0).round()/10.0).collect();
+assert_eq!(vec![-3.1, 0.0, 3.1], roots);
+```
+---
+In this example, `root_search::find_all_roots` finds the root of ln(x) in [-1,2], skipping the negative points at
+which ln(x) is NaN.
+```rust
+# use root_finding::*;
+let res = root_search::find_all_roots(&(|x:f64| -> f64 {x.ln()}), (-1.0,2.0), 30, 1e-6);
+assert_eq!(1, res.len());
+assert_eq!(1.0, (res[0].root*10.0).round()/10.0);
+```
+---
+In this example, `root_search::find_all_roots` finds the roots 0 and π of tan(x) in [-1,4], but not its pole at π/2.
+```rust
+# use root_finding::*;
+let res = root_search::find_all_roots(&(|x:f64| -> f64 {x.tan()}), (-1.0,4.0), 50, 1e-10);
+let roots: Vec<f64> = res.iter().map(|r| (r.root*100.0).round()/100.0).collect();
+assert_eq!(vec![0.0, 3.14], roots);
+```
+*/
+pub fn find_all_roots<T: Float, F: BatchedFunction<T> + ?Sized>(
+    func: &F,
+    domain: (T, T),
+    n_subdivisions: usize,
+    trunc_err: T,
+) -> Vec<RootResult<T>> {
+    trace_span!("find_all_roots");
+    let (start, end): (T, T) = domain;
+    let width: T = (end - start) / T::from(n_subdivisions.max(1)).unwrap();
+    let points: Vec<T> = (0..=n_subdivisions.max(1))
+        .map(|i| start + width * T::from(i).unwrap())
+        .collect();
+    let mut values: Vec<T> = vec![T::zero(); points.len()];
+    func.eval_many(&points, &mut values);
+
+    let mut roots: Vec<RootResult<T>> = Vec::new();
+    for i in 0..points.len() {
+        if values[i].is_zero() {
+            roots.push(RootResult {
+                root: points[i],
+                history: Vec::new(),
+                residuals: Vec::new(),
+                evaluations: 0,
+            });
+        } else if i + 1 < points.len() && !values[i + 1].is_zero() {
+            if let Ok(bracket) =
+                Bracket::from_values(points[i], points[i + 1], values[i], values[i + 1])
+            {
+                let counted: CountedFunction<F> = CountedFunction::new(func);
+                let (last, res) = match binary_with_history(
+                    &counted,
+                    bracket,
+                    trunc_err,
+                    T::zero(),
+                    &Budget::unlimited(),
+                    &Clock::unlimited(),
+                ) {
+                    Ok(found) => found,
+                    Err(_) => {
+                        trace_info!(
+                            lo = points[i].to_f64(),
+                            hi = points[i + 1].to_f64(),
+                            "skipped sub-interval"
+                        );
+                        continue;
+                    }
+                };
+                let initial: T = values[i].abs().max(values[i + 1].abs());
+                if last.f_lo.abs().min(last.f_hi.abs()) > initial {
+                    trace_info!(x = res.root.to_f64(), "rejected pole");
+                } else {
+                    roots.push(res);
+                }
+            }
+        }
+    }
+    trace_info!(roots = roots.len(), "found roots");
+    roots
+}
+
+/**
+Find an interval around an initial guess over which a continuous function changes sign.
+
+Starting from a small interval centred on `x0`, the endpoint at which `func` is smaller in magnitude is repeatedly
+pushed outwards by `factor` times the current width, on the basis that the function is more likely to cross zero on
+that side.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `x0` : An initial guess for the location of the root.
+* `factor` : The factor by which to grow the interval at each step; must be greater than 0.
+* `max_expansions` : The maximum number of times to grow the interval before giving up.
+
+Returns
+-------
+* `Bracket<T>` : An interval over which `func` changes sign.
+
+Errors
+------
+* If `factor` is not positive.
+* If no sign change is found within `max_expansions` expansions.
+* If `func` is NaN at an endpoint, e.g. because the interval has grown past the edge of its domain.
+
+Examples
+--------
+In this example, `root_search::bracket_outward` finds a bracket for `trig` starting from x=0, which we then search.
+```rust
+# use root_finding::*;
+let bracket = root_search::bracket_outward(&test_function::trig, 0.0, 1.6, 50)?;
+let res:f64 = root_search::binary(&test_function::trig, bracket, 0.1, root_search::Budget::unlimited())?.result.root;
+assert_eq!(-2.9, (res*10.0).round()/10.0);
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+pub fn bracket_outward<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    x0: T,
+    factor: T,
+    max_expansions: usize,
+) -> Result<Bracket<T>, &'static str> {
+    trace_span!("bracket_outward");
+    if factor <= T::zero() {
+        return Err("Error: expansion factor must be positive!");
+    }
+
+    let half_width: T = x0.abs().max(T::one()) * T::from(0.01).unwrap();
+    let (mut lo, mut hi): (T, T) = (x0 - half_width, x0 + half_width);
+    let (mut f_lo, mut f_hi): (T, T) = (func.eval(lo), func.eval(hi));
+
+    for _ in 0..=max_expansions {
+        if f_lo.is_nan() || f_hi.is_nan() {
+            return Err("Error: function is NaN at an endpoint!");
+        }
+        trace_debug!(lo = lo.to_f64(), hi = hi.to_f64());
+        if let Ok(bracket) = Bracket::from_values(lo, hi, f_lo, f_hi) {
+            return Ok(bracket);
+        }
+        let step: T = factor * (hi - lo);
+        if f_lo.abs() < f_hi.abs() {
+            lo = lo - step;
+            f_lo = func.eval(lo);
+        } else {
+            hi = hi + step;
+            f_hi = func.eval(hi);
+        }
+    }
+    Err("Error: no sign change found!")
+}
+
+/// The spacing of floating-point numbers at `x`, i.e. the value of the last bit of its mantissa.
+fn ulp<T: Float>(x: T) -> T {
+    let (_, exponent, _) = x.integer_decode();
+    T::from(2.0).unwrap().powi(exponent as i32)
+}
+
+/**
+Refines a root estimate to the limit of floating-point precision, by bisecting down to adjacent representable numbers.
+
+A solver stopping at `trunc_err` typically leaves some accuracy on the table, and rounding its result to `trunc_err`
+throws away more. Here a bracket is sought within `max_ulps` units in the last place of `x`, doubling its width from one
+unit until `func` changes sign over it, and then bisected until its endpoints are adjacent floating-point numbers. The
+endpoint at which `|func|` is smaller is returned. If `func` doesn't change sign within `max_ulps` units, `x` is
+returned unchanged, or whichever point examined has a smaller `|func|`.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `x` : An estimate of a root of `func`, e.g. from another solver.
+* `max_ulps` : How many units in the last place of `x` to search either side of it for a sign change.
+
+Returns
+-------
+* `RootResult<T>` : The refined root, with the midpoints computed and the evaluations of `func`.
+
+Errors
+------
+* `RootError<T>` : If `func` is NaN or infinite at `x` or a point examined, with reason `TerminationReason::NonFinite`.
+
+Examples
+--------
+In this example, `root_search::polish` refines the result of a binary search to tolerance 1e-6 into the root of
+`x^2 - 2` to within one unit in the last place.
+```rust
+# use root_finding::*;
+let func = |x: f64| x * x - 2.0;
+let bracket = root_search::Bracket::new(&func, 1.0, 2.0).unwrap();
+let rough: f64 = root_search::binary(&func, bracket, 1e-6, root_search::Budget::unlimited())?.result.root;
+let res = root_search::polish(&func, rough, 1 << 40)?;
+assert!((res.root - 2.0_f64.sqrt()).abs() <= f64::EPSILON);
+# Ok::<(), root_search::RootError>(())
+```
+*/
+pub fn polish<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    x: T,
+    max_ulps: u64,
+) -> Result<RootResult<T>, RootError<T>> {
+    trace_span!("polish");
+    let func: CountedFunction<F> = CountedFunction::new(func);
+    let mut history: Vec<T> = Vec::new();
+    let mut residuals: Vec<T> = Vec::new();
+    let eval = |x: T, history: &[T], residuals: &[T]| -> Result<T, RootError<T>> {
+        let val: T = func.call(x);
+        if val.is_finite() {
+            Ok(val)
+        } else {
+            let mut history: Vec<T> = history.to_vec();
+            let mut residuals: Vec<T> = residuals.to_vec();
+            history.push(x);
+            residuals.push(val);
+            Err(RootError {
+                reason: TerminationReason::NonFinite,
+                history,
+                residuals,
+                evaluations: func.evaluations(),
+            })
+        }
+    };
+    let f_x: T = eval(x, &history, &residuals)?;
+    let mut best: (T, T) = (x, f_x);
+
+    let unit: T = ulp(x).max(T::min_positive_value());
+    let mut bracket: Option<Bracket<T>> = None;
+    let mut ulps: u64 = 1;
+    while f_x != T::zero() && ulps <= max_ulps {
+        let step: T = unit * T::from(ulps).unwrap();
+        for &end in &[x - step, x + step] {
+            let f_end: T = eval(end, &history, &residuals)?;
+            if f_end.abs() < best.1.abs() {
+                best = (end, f_end);
+            }
+            if let Ok(found) = Bracket::from_values(x, end, f_x, f_end) {
+                bracket = Some(found);
+                break;
+            }
+        }
+        if bracket.is_some() || ulps == u64::MAX {
+            break;
+        }
+        ulps = ulps.saturating_mul(2);
+    }
+
+    if let Some(mut bracket) = bracket {
+        loop {
+            let midpoint: T = bracket.midpoint();
+            if midpoint <= bracket.lo || midpoint >= bracket.hi {
+                break;
+            }
+            let test_val: T = eval(midpoint, &history, &residuals)?;
+            history.push(midpoint);
+            residuals.push(test_val);
+            if test_val.is_zero() {
+                bracket = Bracket::exact(midpoint);
+                break;
+            }
+            if sgn(test_val) == sgn(bracket.f_lo) {
+                bracket.lo = midpoint;
+                bracket.f_lo = test_val;
+            } else {
+                bracket.hi = midpoint;
+                bracket.f_hi = test_val;
+            }
+        }
+        best = if bracket.f_lo.abs() <= bracket.f_hi.abs() {
+            (bracket.lo, bracket.f_lo)
+        } else {
+            (bracket.hi, bracket.f_hi)
+        };
+    }
+
+    traced(Ok(RootResult {
+        root: best.0,
+        history,
+        residuals,
+        evaluations: func.evaluations(),
+    }))
+}
+
+/// The factor by which `invert` grows a bracket around an initial guess.
+const INVERT_FACTOR: f64 = 1.6;
+
+/// The maximum number of times `invert` grows a bracket around an initial guess.
+const INVERT_EXPANSIONS: usize = 50;
+
+/// Where `invert` should look for a solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DomainOrGuess<T = f64> {
+    /// An interval `(start, end)` over which `F(x) - y` changes sign.
+    Domain((T, T)),
+    /// An initial guess, around which a bracket is grown by `bracket_outward`.
+    Guess(T),
+}
+
+/// Why `invert` failed to solve `F(x) = y`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InvertError<T = f64> {
+    /// `F(x) - y` doesn't change sign over the domain, or no bracket could be grown around the guess.
+    NoBracket {
+        /// Why no bracket was found, as given by `Bracket::new` or `bracket_outward`.
+        message: String,
+        /// The number of evaluations of `F` made looking for a bracket.
+        evaluations: usize,
+    },
+    /// The binary search over the bracket failed, e.g. at a point where `F` is not finite, with the midpoints computed
+    /// and every evaluation of `F`, including those made finding the bracket.
+    Search(RootError<T>),
+}
+
+impl<T> From<RootError<T>> for InvertError<T> {
+    fn from(err: RootError<T>) -> InvertError<T> {
+        InvertError::Search(err)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for InvertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InvertError::NoBracket { ref message, .. } => write!(f, "{}", message),
+            InvertError::Search(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for InvertError<T> {}
+
+/**
+Solves `F(x) = y` for `x`, e.g. to evaluate an inverse function, or the quantile function of a CDF.
+
+The function is shifted to `F(x) - y`, and a root of that found by binary search. Given a domain, it is searched
+directly; given an initial guess, a bracket is first grown around it by `bracket_outward`. Bisection needs nothing of
+`F` but continuity, and its error is bounded by the final bracket, which suits the monotone functions usually inverted.
+
+Parameters
+----------
+* `func` : A continuous function.
+* `y` : The value of `func` sought.
+* `start` : A domain over which `func - y` changes sign, or an initial guess near the solution.
+* `trunc_err` : A float representing the acceptable truncation error for the solution. If it is smaller than the spacing
+  of floats near the solution, the search stops at adjacent floats, so it may be 0.
+
+Returns
+-------
+* `RootResult<T>` : The solution `x`, with the midpoints computed by the binary search and the evaluations of `func`,
+  including those made in growing the bracket.
+
+Errors
+------
+* `InvertError::NoBracket` : If `func - y` doesn't change sign over the domain, or no bracket can be grown around the guess.
+* `InvertError::Search` : If `func` is NaN or infinite at a midpoint of the binary search, with the `RootError` recording
+  where.
+
+Examples
+--------
+In this example, `root_search::invert` computes ln(2) by inverting `exp`, first over a domain and then from a guess,
+and then to full precision.
+```rust
+# use root_finding::*;
+use root_finding::root_search::DomainOrGuess;
+let res = root_search::invert(&(|x: f64| x.exp()), 2.0, DomainOrGuess::Domain((0.0, 1.0)), 1e-12)?;
+assert!((res.root - 2.0_f64.ln()).abs() < 1e-12);
+let res = root_search::invert(&(|x: f64| x.exp()), 2.0, DomainOrGuess::Guess(5.0), 1e-12)?;
+assert!((res.root - 2.0_f64.ln()).abs() < 1e-12);
+let res = root_search::invert(&(|x: f64| x.exp()), 2.0, DomainOrGuess::Guess(5.0), 0.0)?;
+assert!((res.root - 2.0_f64.ln()).abs() <= f64::EPSILON);
+# Ok::<(), root_search::InvertError>(())
+```
+---
+In this example, `root_search::invert` is given a function which is -1 at 4, but NaN on (1, 4), so the first midpoint
+of the domain [0, 4] is NaN, and the error records where.
+```rust
+# use root_finding::*;
+use root_finding::root_search::{DomainOrGuess, InvertError, TerminationReason};
+let sqrt = |x: f64| if x == 4.0 { -1.0 } else { (1.0 - x).sqrt() };
+match root_search::invert(&sqrt, 0.5, DomainOrGuess::Domain((0.0, 4.0)), 1e-12) {
+    Err(InvertError::Search(err)) => {
+        assert_eq!(TerminationReason::NonFinite, err.reason);
+        assert_eq!(Some(&2.0), err.non_finite_at());
+    }
+    res => panic!("expected a failed search, got {:?}", res),
+}
+```
+*/
+pub fn invert<T: Float, F: ObjectiveFn<T> + ?Sized>(
+    func: &F,
+    y: T,
+    start: DomainOrGuess<T>,
+    trunc_err: T,
+) -> Result<RootResult<T>, InvertError<T>> {
+    trace_span!("invert");
+    let shifted = |x: T| -> T { func.eval(x) - y };
+    let counted = CountedFunction::new(&shifted);
+    let bracket: Result<Bracket<T>, &'static str> = match start {
+        DomainOrGuess::Domain((a, b)) => Bracket::new(&|x: T| counted.call(x), a, b),
+        DomainOrGuess::Guess(x0) => bracket_outward(
+            &|x: T| counted.call(x),
+            x0,
+            T::from(INVERT_FACTOR).unwrap(),
+            INVERT_EXPANSIONS,
+        ),
+    };
+    let bracket: Bracket<T> = bracket.map_err(|message| InvertError::NoBracket {
+        message: message.to_string(),
+        evaluations: counted.evaluations(),
+    })?;
+    Ok(binary_with_history(
+        &counted,
+        bracket,
+        trunc_err,
+        T::zero(),
+        &Budget::unlimited(),
+        &Clock::unlimited(),
+    )
+    .map(|(_, res)| res)?)
+}
+
+/**
+How `with_restarts` retries a failed search.
+
+Each restart draws a new initial guess uniformly from `interval`, using a generator seeded with `seed`, so a run can be
+reproduced exactly, on any platform, from its policy.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RestartPolicy<T = f64> {
+    /// The interval `(start, end)` from which new initial guesses are drawn.
+    pub interval: (T, T),
+    /// The maximum number of restarts after the first attempt.
+    pub max_restarts: usize,
+    /// The seed of the generator drawing the new initial guesses.
+    pub seed: u64,
+}
+
+/// One run of the solver made by `with_restarts`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Attempt<T = f64> {
+    /// The initial guess the solver was started from.
+    pub initial_val: T,
+    pub outcome: Result<RootResult<T>, RootError<T>>,
+}
+
+/// The outcome of `with_restarts`: every attempt made, in order. Only the last can have succeeded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Restarted<T = f64> {
+    pub attempts: Vec<Attempt<T>>,
+}
+
+impl<T> Restarted<T> {
+    /// The outcome of the last attempt: the root found, or why the search stopped if every attempt failed. This is
+    /// `None` if there were no attempts, which `with_restarts` never returns, but a deserialised value may hold.
+    pub fn outcome(&self) -> Option<Result<&RootResult<T>, &RootError<T>>> {
+        self.attempts.last().map(|attempt| attempt.outcome.as_ref())
+    }
+
+    /// The total number of function evaluations made, over every attempt.
+    pub fn evaluations(&self) -> usize {
+        self.attempts
+            .iter()
+            .map(|attempt| match attempt.outcome {
+                Ok(ref res) => res.evaluations,
+                Err(ref err) => err.evaluations,
+            })
+            .sum()
+    }
+}
+
+/// The SplitMix64 generator: small, fast, and fully determined by its seed, unlike generators whose output may change
+/// between versions of a library.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A point drawn uniformly from `[start, end)`.
+    fn uniform<T: Float>(&mut self, (start, end): (T, T)) -> T {
+        // The top 53 bits, as a fraction in [0, 1) exactly representable as an `f64`.
+        let fraction: f64 = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        start + (end - start) * T::from(fraction).unwrap()
+    }
+}
+
+/**
+Runs a root-finder, restarting it from random initial guesses while it fails.
+
+Methods such as fixed point iteration and Newton-Raphson converge only from good enough initial guesses, and fail
+outright from bad ones. Here the solver is first run from `initial_val`, and if it fails, rerun from up to
+`policy.max_restarts` guesses drawn uniformly from `policy.interval`, stopping at the first success.
+
+Parameters
+----------
+* `solver` : A root-finder taking a starting point, with the same return type as `root_search::fixed_point`.
+* `initial_val` : The initial guess for the first attempt.
+* `policy` : Where to draw new initial guesses, how many times to restart, and the seed of the generator.
+
+Returns
+-------
+* `Restarted<T>` : Every attempt made, with its initial guess and outcome. The last is a success if any attempt was.
+
+Examples
+--------
+In this example, Newton-Raphson on `x^3 - 2x + 2` cycles from 0, so is restarted from a point in [-3, 3].
+```rust
+# use root_finding::*;
+use root_finding::root_search::RestartPolicy;
+let solver = |x0: f64| {
+    let newton = functional::x_minus(functional::newton_raphson(
+        |x: f64| x.powi(3) - 2.0 * x + 2.0,
+        |x: f64| 3.0 * x.powi(2) - 2.0,
+    ));
+    root_search::fixed_point(&newton, x0, 1e-12, root_search::Budget::new(100, None))
+};
+let policy = RestartPolicy { interval: (-3.0, 3.0), max_restarts: 10, seed: 1 };
+let res = root_search::with_restarts(&solver, 0.0, &policy);
+assert!(res.attempts.len() > 1);
+let root: f64 = res.outcome().unwrap().unwrap().root;
+assert!((root + 1.7692923542386314).abs() < 1e-12);
+assert_eq!(res, root_search::with_restarts(&solver, 0.0, &policy));
+```
+*/
+pub fn with_restarts<T: Float, S: Fn(T) -> Result<RootResult<T>, RootError<T>> + ?Sized>(
+    solver: &S,
+    initial_val: T,
+    policy: &RestartPolicy<T>,
+) -> Restarted<T> {
+    trace_span!("with_restarts");
+    let mut rng: SplitMix64 = SplitMix64 { state: policy.seed };
+    let mut attempts: Vec<Attempt<T>> = Vec::with_capacity(1);
+    let mut x0: T = initial_val;
+    loop {
+        let outcome: Result<RootResult<T>, RootError<T>> = solver(x0);
+        let failed: bool = outcome.is_err();
+        trace_debug!(attempt = attempts.len(), x0 = x0.to_f64(), failed);
+        attempts.push(Attempt {
+            initial_val: x0,
+            outcome,
+        });
+        if !failed || attempts.len() > policy.max_restarts {
+            break;
+        }
+        x0 = rng.uniform(policy.interval);
+    }
+    trace_info!(attempts = attempts.len(), "finished restarts");
+    Restarted { attempts }
+}
+
+/// The outcome of `multi_start`: the distinct roots found, and the run from each initial guess.
+#[cfg(feature = "rayon")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiStart<T = f64> {
+    /// The distinct roots found, in increasing order.
+    pub roots: Vec<T>,
+    /// The run from each initial guess, in the order of the guesses.
+    pub runs: Vec<Result<RootResult<T>, RootError<T>>>,
+    /// For each run, the index into `roots` of the root it converged to, or `None` if it failed or its root is NaN or
+    /// infinite.
+    pub assignments: Vec<Option<usize>>,
+}
+
+/**
+Runs a root-finder from each of many initial guesses in parallel, and collects the distinct roots found.
+
+This is a brute-force way to hunt for every root of a function with many, such as an oscillatory one, using a method
+that needs no bracket. The runs are spread over a `rayon` thread pool. Converged roots are then sorted, and any root
+within `tol` of the previous one is taken to be the same root, so `roots` holds the smallest root of each cluster.
+A run which reports a NaN or infinite root is kept in `runs`, but its root is left out of `roots`.
+
+Only available with the `rayon` feature.
+
+Parameters
+----------
+* `solver` : A root-finder taking a starting point, with the same return type as `root_search::fixed_point`. It is
+  shared between threads, so should build any functionals it needs itself rather than borrowing them.
+* `starts` : The initial guesses.
+* `tol` : How close two roots must be to count as the same root.
+
+Returns
+-------
+* `MultiStart<T>` : The distinct roots found, with the run from each initial guess and the root it converged to.
+
+Examples
+--------
+In this example, `root_search::multi_start` runs Newton-Raphson on sin(x) from 41 points in [-10, 10], and finds the
+seven roots of sin(x) in [-3pi, 3pi].
+```rust
+# use root_finding::*;
+let solver = |x0: f64| {
+    let newton = functional::x_minus(functional::newton_raphson(&test_function::sine, &test_func_derivative::sine));
+    root_search::fixed_point(&newton, x0, 1e-12, root_search::Budget::new(100, None))
+};
+let starts: Vec<f64> = (0..=40).map(|i| -10.0 + i as f64 / 2.0).collect();
+let res = root_search::multi_start(&solver, &starts, 1e-6);
+let in_range: Vec<f64> = res.roots.iter().cloned().filter(|r| r.abs() < 10.0).collect();
+assert_eq!(7, in_range.len());
+assert_eq!(res.runs.len(), res.assignments.len());
+```
+---
+In this example, a solver which reports a NaN root from some starting points is run, and the NaN is left out.
+```rust
+# use root_finding::*;
+use root_finding::root_search::RootResult;
+let solver = |x0: f64| {
+    let root: f64 = if x0 < 0.0 { f64::NAN } else { 1.0 };
+    Ok(RootResult { root, history: vec![x0], residuals: vec![0.0], evaluations: 1 })
+};
+let res = root_search::multi_start(&solver, &[-1.0, 2.0, 3.0], 1e-6);
+assert_eq!(vec![1.0], res.roots);
+assert_eq!(vec![None, Some(0), Some(0)], res.assignments);
+```
+*/
+#[cfg(feature = "rayon")]
+pub fn multi_start<T: Float + Send + Sync>(
+    solver: &(dyn Fn(T) -> Result<RootResult<T>, RootError<T>> + Sync),
+    starts: &[T],
+    tol: T,
+) -> MultiStart<T> {
+    trace_span!("multi_start");
+    let runs: Vec<Result<RootResult<T>, RootError<T>>> =
+        starts.par_iter().map(|&x0| solver(x0)).collect();
+
+    let mut found: Vec<T> = runs
+        .iter()
+        .filter_map(|run| run.as_ref().ok().map(|res| res.root))
+        .filter(|root| root.is_finite())
+        .collect();
+    found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut roots: Vec<T> = Vec::new();
+    let mut last: Option<T> = None;
+    for root in found {
+        if last.is_none_or(|last| root - last >= tol) {
+            roots.push(root);
+        }
+        last = Some(root);
+    }
+
+    let assignments: Vec<Option<usize>> = runs
+        .iter()
+        .map(|run| {
+            run.as_ref()
+                .ok()
+                .filter(|res| res.root.is_finite())
+                .map(|res| {
+                    // The last distinct root not above this one, which is the root of its cluster.
+                    roots
+                        .iter()
+                        .rposition(|&root| root <= res.root)
+                        .unwrap_or(0)
+                })
+        })
+        .collect();
+
+    trace_info!(starts = starts.len(), roots = roots.len(), "found roots");
+    MultiStart {
+        roots,
+        runs,
+        assignments,
+    }
 }
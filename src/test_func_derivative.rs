@@ -6,16 +6,38 @@ Functions
 * `identity` : Returns the input. Has a root at `x=0`.
 * `polynom` : A cubic polynomial. Has roots at `x=0.5` and `x=4`.
 * `trig` : The sum of a linear polynomial and a sinosoidal function. Has a root at `x=-2.88...`
+* `exp_minus_two` : `e^x - 2`.
+* `x_exp` : `xe^-x`.
+* `cos_minus_x` : `cos(x) - x`.
+* `sine` : `sin(x)`.
+* `triple` : `(x-1)^3`.
+* `wilkinson` : Wilkinson's polynomial `(x-1)(x-2)...(x-10)`.
+* `x_minus_exp` : `x - e^-x`.
+* `newton_cubic` : `x^3 - 2x - 5`.
+* `double_root` : `x^3 - 3x + 2`.
+
+The `generic` submodule provides the same derivatives over any `num::Float` type.
 */
 
-use pow;
+#![allow(clippy::needless_return, clippy::let_and_return)]
+
+use polynomial::Polynomial;
+use std::sync::LazyLock;
+use test_function;
+
+/// The derivative of `test_function::POLYNOM`, from which `polynom` is evaluated.
+pub static POLYNOM: LazyLock<Polynomial> = LazyLock::new(|| test_function::POLYNOM.derivative());
+
+/// The derivative of `test_function::WILKINSON`, from which `wilkinson` is evaluated.
+pub static WILKINSON: LazyLock<Polynomial> =
+    LazyLock::new(|| test_function::WILKINSON.derivative());
 
 pub fn identity(_x: f64) -> f64 {
     return 1.0;
 }
 
 pub fn polynom(x: f64) -> f64 {
-    let res: f64 = 3.0 * pow(x, 2) - 17.0 * x + 20.0;
+    let res: f64 = POLYNOM.eval(x);
     return res;
 }
 
@@ -23,3 +45,94 @@ pub fn trig(x: f64) -> f64 {
     let res: f64 = 2.0 - 3.0 * x.cos();
     return res;
 }
+
+pub fn exp_minus_two(x: f64) -> f64 {
+    x.exp()
+}
+
+pub fn x_exp(x: f64) -> f64 {
+    (1.0 - x) * (-x).exp()
+}
+
+pub fn cos_minus_x(x: f64) -> f64 {
+    -x.sin() - 1.0
+}
+
+pub fn sine(x: f64) -> f64 {
+    x.cos()
+}
+
+pub fn triple(x: f64) -> f64 {
+    3.0 * (x - 1.0).powi(2)
+}
+
+pub fn wilkinson(x: f64) -> f64 {
+    WILKINSON.eval(x)
+}
+
+pub fn x_minus_exp(x: f64) -> f64 {
+    1.0 + (-x).exp()
+}
+
+pub fn newton_cubic(x: f64) -> f64 {
+    3.0 * x.powi(2) - 2.0
+}
+
+pub fn double_root(x: f64) -> f64 {
+    3.0 * x.powi(2) - 3.0
+}
+
+/**
+Generic counterparts of the test function derivatives, for use with e.g. `f32`.
+*/
+pub mod generic {
+    use Float;
+
+    pub fn identity<T: Float>(_x: T) -> T {
+        T::one()
+    }
+
+    pub fn polynom<T: Float>(x: T) -> T {
+        super::POLYNOM.eval(x)
+    }
+
+    pub fn trig<T: Float>(x: T) -> T {
+        T::from(2.0).unwrap() - T::from(3.0).unwrap() * x.cos()
+    }
+
+    pub fn exp_minus_two<T: Float>(x: T) -> T {
+        x.exp()
+    }
+
+    pub fn x_exp<T: Float>(x: T) -> T {
+        (T::one() - x) * (-x).exp()
+    }
+
+    pub fn cos_minus_x<T: Float>(x: T) -> T {
+        -x.sin() - T::one()
+    }
+
+    pub fn sine<T: Float>(x: T) -> T {
+        x.cos()
+    }
+
+    pub fn triple<T: Float>(x: T) -> T {
+        T::from(3.0).unwrap() * (x - T::one()).powi(2)
+    }
+
+    pub fn wilkinson<T: Float>(x: T) -> T {
+        super::WILKINSON.eval(x)
+    }
+
+    pub fn x_minus_exp<T: Float>(x: T) -> T {
+        T::one() + (-x).exp()
+    }
+
+    pub fn newton_cubic<T: Float>(x: T) -> T {
+        T::from(3.0).unwrap() * x.powi(2) - T::from(2.0).unwrap()
+    }
+
+    pub fn double_root<T: Float>(x: T) -> T {
+        T::from(3.0).unwrap() * x.powi(2) - T::from(3.0).unwrap()
+    }
+}
@@ -0,0 +1,135 @@
+/*!
+The second derivatives of 'test functions', for use in e.g. Halley's method.
+
+Functions
+---------
+* `identity` : Returns the input. Has a root at `x=0`.
+* `polynom` : A cubic polynomial. Has roots at `x=0.5` and `x=4`.
+* `trig` : The sum of a linear polynomial and a sinosoidal function. Has a root at `x=-2.88...`
+* `exp_minus_two` : `e^x - 2`.
+* `x_exp` : `xe^-x`.
+* `cos_minus_x` : `cos(x) - x`.
+* `sine` : `sin(x)`.
+* `triple` : `(x-1)^3`.
+* `wilkinson` : Wilkinson's polynomial `(x-1)(x-2)...(x-10)`.
+* `x_minus_exp` : `x - e^-x`.
+* `newton_cubic` : `x^3 - 2x - 5`.
+* `double_root` : `x^3 - 3x + 2`.
+
+The `generic` submodule provides the same second derivatives over any `num::Float` type.
+*/
+
+use polynomial::Polynomial;
+use std::sync::LazyLock;
+use test_func_derivative;
+
+/// The second derivative of `test_function::POLYNOM`, from which `polynom` is evaluated.
+pub static POLYNOM: LazyLock<Polynomial> =
+    LazyLock::new(|| test_func_derivative::POLYNOM.derivative());
+
+/// The second derivative of `test_function::WILKINSON`, from which `wilkinson` is evaluated.
+pub static WILKINSON: LazyLock<Polynomial> =
+    LazyLock::new(|| test_func_derivative::WILKINSON.derivative());
+
+pub fn identity(_x: f64) -> f64 {
+    0.0
+}
+
+pub fn polynom(x: f64) -> f64 {
+    POLYNOM.eval(x)
+}
+
+pub fn trig(x: f64) -> f64 {
+    3.0 * x.sin()
+}
+
+pub fn exp_minus_two(x: f64) -> f64 {
+    x.exp()
+}
+
+pub fn x_exp(x: f64) -> f64 {
+    (x - 2.0) * (-x).exp()
+}
+
+pub fn cos_minus_x(x: f64) -> f64 {
+    -x.cos()
+}
+
+pub fn sine(x: f64) -> f64 {
+    -x.sin()
+}
+
+pub fn triple(x: f64) -> f64 {
+    6.0 * (x - 1.0)
+}
+
+pub fn wilkinson(x: f64) -> f64 {
+    WILKINSON.eval(x)
+}
+
+pub fn x_minus_exp(x: f64) -> f64 {
+    -(-x).exp()
+}
+
+pub fn newton_cubic(x: f64) -> f64 {
+    6.0 * x
+}
+
+pub fn double_root(x: f64) -> f64 {
+    6.0 * x
+}
+
+/**
+Generic counterparts of the test function second derivatives, for use with e.g. `f32`.
+*/
+pub mod generic {
+    use Float;
+
+    pub fn identity<T: Float>(_x: T) -> T {
+        T::zero()
+    }
+
+    pub fn polynom<T: Float>(x: T) -> T {
+        super::POLYNOM.eval(x)
+    }
+
+    pub fn trig<T: Float>(x: T) -> T {
+        T::from(3.0).unwrap() * x.sin()
+    }
+
+    pub fn exp_minus_two<T: Float>(x: T) -> T {
+        x.exp()
+    }
+
+    pub fn x_exp<T: Float>(x: T) -> T {
+        (x - T::from(2.0).unwrap()) * (-x).exp()
+    }
+
+    pub fn cos_minus_x<T: Float>(x: T) -> T {
+        -x.cos()
+    }
+
+    pub fn sine<T: Float>(x: T) -> T {
+        -x.sin()
+    }
+
+    pub fn triple<T: Float>(x: T) -> T {
+        T::from(6.0).unwrap() * (x - T::one())
+    }
+
+    pub fn wilkinson<T: Float>(x: T) -> T {
+        super::WILKINSON.eval(x)
+    }
+
+    pub fn x_minus_exp<T: Float>(x: T) -> T {
+        -(-x).exp()
+    }
+
+    pub fn newton_cubic<T: Float>(x: T) -> T {
+        T::from(6.0).unwrap() * x
+    }
+
+    pub fn double_root<T: Float>(x: T) -> T {
+        T::from(6.0).unwrap() * x
+    }
+}
@@ -6,16 +6,50 @@ Functions
 * `identity` : Returns the input. Has a root at `x=0`.
 * `polynom` : A cubic polynomial. Has roots at `x=0.5` and `x=4`.
 * `trig` : The sum of a linear polynomial and a sinosoidal function. Has a root at `x=-2.88...`
+* `exp_minus_two` : `e^x - 2`. Has a root at `x=ln(2)`.
+* `x_exp` : `xe^-x`. Has a root at `x=0`, and tends to 0 without crossing it as `x` grows.
+* `cos_minus_x` : `cos(x) - x`. Has a root at the Dottie number, `x=0.739...`
+* `sine` : `sin(x)`. Has roots at every multiple of pi.
+* `triple` : `(x-1)^3`. Has a triple root at `x=1`.
+* `wilkinson` : Wilkinson's polynomial `(x-1)(x-2)...(x-10)`, stored by its coefficients. Has roots at `x=1,...,10`.
+* `x_minus_exp` : `x - e^-x`. Has a root at the omega constant, `x=W(1)=0.567...`
+* `newton_cubic` : `x^3 - 2x - 5`, the example Newton first applied his method to. Has a root at `x=2.094...`
+* `double_root` : `x^3 - 3x + 2 = (x-1)^2(x+2)`. Has a double root at `x=1` and a simple root at `x=-2`.
+
+The first and second derivatives of each are in `test_func_derivative` and `test_func_second_derivative`, under the
+same names.
+
+The `generic` submodule provides the same functions over any `num::Float` type.
+
+Types
+-----
+* `TestProblem` : A test function together with its first two derivatives, a search interval and its known roots.
+
+`PROBLEMS` lists every test function as a `TestProblem`, so that solvers can be checked against known roots.
 */
 
-use pow;
+#![allow(clippy::needless_return, clippy::let_and_return)]
+
+use polynomial::Polynomial;
+use std::f64::consts::{LN_2, PI};
+use std::sync::LazyLock;
+use test_func_derivative;
+use test_func_second_derivative;
+
+/// The cubic `x^3 - 8.5x^2 + 20x - 8`, from which `polynom` is evaluated.
+pub static POLYNOM: LazyLock<Polynomial> =
+    LazyLock::new(|| Polynomial::new(vec![-8.0, 20.0, -8.5, 1.0]));
+
+/// Wilkinson's polynomial of degree 10, from which `wilkinson` is evaluated.
+pub static WILKINSON: LazyLock<Polynomial> =
+    LazyLock::new(|| Polynomial::from_roots(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]));
 
 pub fn identity(x: f64) -> f64 {
     return x;
 }
 
 pub fn polynom(x: f64) -> f64 {
-    let res: f64 = pow(x, 3) - 8.5 * pow(x, 2) + 20.0 * x - 8.0;
+    let res: f64 = POLYNOM.eval(x);
     return res;
 }
 
@@ -23,3 +57,264 @@ pub fn trig(x: f64) -> f64 {
     let res: f64 = 2.0 * x - 3.0 * x.sin() + 5.0;
     return res;
 }
+
+pub fn exp_minus_two(x: f64) -> f64 {
+    x.exp() - 2.0
+}
+
+pub fn x_exp(x: f64) -> f64 {
+    x * (-x).exp()
+}
+
+pub fn cos_minus_x(x: f64) -> f64 {
+    x.cos() - x
+}
+
+pub fn sine(x: f64) -> f64 {
+    x.sin()
+}
+
+pub fn triple(x: f64) -> f64 {
+    (x - 1.0).powi(3)
+}
+
+pub fn wilkinson(x: f64) -> f64 {
+    WILKINSON.eval(x)
+}
+
+pub fn x_minus_exp(x: f64) -> f64 {
+    x - (-x).exp()
+}
+
+pub fn newton_cubic(x: f64) -> f64 {
+    x.powi(3) - 2.0 * x - 5.0
+}
+
+pub fn double_root(x: f64) -> f64 {
+    x.powi(3) - 3.0 * x + 2.0
+}
+
+/**
+A test function with known roots, for checking solvers against ground truth.
+
+Examples
+--------
+In this example, we check `root_search::find_all_roots` against every problem with only simple roots.
+```rust
+# use root_finding::*;
+for problem in test_function::PROBLEMS.iter().filter(|p| p.simple_roots()) {
+    let found: Vec<f64> = root_search::find_all_roots(&problem.function, problem.domain, 1000, 1e-12)
+        .iter()
+        .map(|res| res.root)
+        .collect();
+    assert_eq!(problem.roots.len(), found.len(), "{}", problem.name);
+}
+```
+---
+In this example, we check every problem's derivatives against central differences.
+```rust
+# use root_finding::*;
+let h: f64 = 1e-5;
+for problem in test_function::PROBLEMS.iter() {
+    let x: f64 = (problem.domain.0 + problem.domain.1) / 2.0 + 0.1;
+    let slope = |f: fn(f64) -> f64| (f(x + h) - f(x - h)) / (2.0 * h);
+    let (deriv, second_deriv) = ((problem.derivative)(x), (problem.second_derivative)(x));
+    assert!((slope(problem.function) - deriv).abs() < 1e-4 * deriv.abs().max(1.0), "{}", problem.name);
+    assert!((slope(problem.derivative) - second_deriv).abs() < 1e-4 * second_deriv.abs().max(1.0), "{}", problem.name);
+}
+```
+*/
+#[derive(Debug, Clone, Copy)]
+pub struct TestProblem {
+    pub name: &'static str,
+    pub function: fn(f64) -> f64,
+    pub derivative: fn(f64) -> f64,
+    pub second_derivative: fn(f64) -> f64,
+    /// An interval containing every listed root, over which the function changes sign.
+    pub domain: (f64, f64),
+    /// The roots in `domain`, in increasing order and repeated according to multiplicity.
+    pub roots: &'static [f64],
+}
+
+impl TestProblem {
+    /// Whether every root is simple, i.e. no root is repeated.
+    pub fn simple_roots(&self) -> bool {
+        self.roots.windows(2).all(|pair| pair[0] != pair[1])
+    }
+
+    /// The distance from `x` to the nearest known root.
+    pub fn distance_to_root(&self, x: f64) -> f64 {
+        self.roots
+            .iter()
+            .map(|root| (x - root).abs())
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Every test function, with its first two derivatives, a search interval and its known roots.
+pub static PROBLEMS: [TestProblem; 12] = [
+    TestProblem {
+        name: "identity",
+        function: identity,
+        derivative: test_func_derivative::identity,
+        second_derivative: test_func_second_derivative::identity,
+        domain: (-1.0, 2.0),
+        roots: &[0.0],
+    },
+    TestProblem {
+        name: "trig",
+        function: trig,
+        derivative: test_func_derivative::trig,
+        second_derivative: test_func_second_derivative::trig,
+        domain: (-4.0, 0.0),
+        roots: &[-2.8832368725582835],
+    },
+    TestProblem {
+        name: "polynom",
+        function: polynom,
+        derivative: test_func_derivative::polynom,
+        second_derivative: test_func_second_derivative::polynom,
+        domain: (0.0, 5.0),
+        roots: &[0.5, 4.0, 4.0],
+    },
+    TestProblem {
+        name: "exp_minus_two",
+        function: exp_minus_two,
+        derivative: test_func_derivative::exp_minus_two,
+        second_derivative: test_func_second_derivative::exp_minus_two,
+        domain: (0.0, 2.0),
+        roots: &[LN_2],
+    },
+    TestProblem {
+        name: "x_exp",
+        function: x_exp,
+        derivative: test_func_derivative::x_exp,
+        second_derivative: test_func_second_derivative::x_exp,
+        domain: (-1.0, 2.0),
+        roots: &[0.0],
+    },
+    TestProblem {
+        name: "cos_minus_x",
+        function: cos_minus_x,
+        derivative: test_func_derivative::cos_minus_x,
+        second_derivative: test_func_second_derivative::cos_minus_x,
+        domain: (0.0, 1.0),
+        roots: &[0.7390851332151607],
+    },
+    TestProblem {
+        name: "sine",
+        function: sine,
+        derivative: test_func_derivative::sine,
+        second_derivative: test_func_second_derivative::sine,
+        domain: (-1.0, 7.0),
+        roots: &[0.0, PI, 2.0 * PI],
+    },
+    TestProblem {
+        name: "triple",
+        function: triple,
+        derivative: test_func_derivative::triple,
+        second_derivative: test_func_second_derivative::triple,
+        domain: (0.0, 3.0),
+        roots: &[1.0, 1.0, 1.0],
+    },
+    TestProblem {
+        name: "wilkinson",
+        function: wilkinson,
+        derivative: test_func_derivative::wilkinson,
+        second_derivative: test_func_second_derivative::wilkinson,
+        domain: (0.5, 10.5),
+        roots: &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
+    },
+    TestProblem {
+        name: "x_minus_exp",
+        function: x_minus_exp,
+        derivative: test_func_derivative::x_minus_exp,
+        second_derivative: test_func_second_derivative::x_minus_exp,
+        domain: (0.0, 1.0),
+        roots: &[0.5671432904097838],
+    },
+    TestProblem {
+        name: "newton_cubic",
+        function: newton_cubic,
+        derivative: test_func_derivative::newton_cubic,
+        second_derivative: test_func_second_derivative::newton_cubic,
+        domain: (2.0, 3.0),
+        roots: &[2.0945514815423265],
+    },
+    TestProblem {
+        name: "double_root",
+        function: double_root,
+        derivative: test_func_derivative::double_root,
+        second_derivative: test_func_second_derivative::double_root,
+        domain: (-3.0, 2.0),
+        roots: &[-2.0, 1.0, 1.0],
+    },
+];
+
+/// The test problem called `name`, if there is one.
+pub fn problem(name: &str) -> Option<&'static TestProblem> {
+    PROBLEMS.iter().find(|problem| problem.name == name)
+}
+
+/**
+Generic counterparts of the test functions, for use with e.g. `f32`.
+
+Examples
+--------
+```rust
+# use root_finding::*;
+let res:f32 = test_function::generic::trig(-3.0_f32);
+assert_eq!(-0.6, (res*10.0).round()/10.0);
+```
+*/
+pub mod generic {
+    use Float;
+
+    pub fn identity<T: Float>(x: T) -> T {
+        x
+    }
+
+    pub fn polynom<T: Float>(x: T) -> T {
+        super::POLYNOM.eval(x)
+    }
+
+    pub fn trig<T: Float>(x: T) -> T {
+        T::from(2.0).unwrap() * x - T::from(3.0).unwrap() * x.sin() + T::from(5.0).unwrap()
+    }
+
+    pub fn exp_minus_two<T: Float>(x: T) -> T {
+        x.exp() - T::from(2.0).unwrap()
+    }
+
+    pub fn x_exp<T: Float>(x: T) -> T {
+        x * (-x).exp()
+    }
+
+    pub fn cos_minus_x<T: Float>(x: T) -> T {
+        x.cos() - x
+    }
+
+    pub fn sine<T: Float>(x: T) -> T {
+        x.sin()
+    }
+
+    pub fn triple<T: Float>(x: T) -> T {
+        (x - T::one()).powi(3)
+    }
+
+    pub fn wilkinson<T: Float>(x: T) -> T {
+        super::WILKINSON.eval(x)
+    }
+
+    pub fn x_minus_exp<T: Float>(x: T) -> T {
+        x - (-x).exp()
+    }
+
+    pub fn newton_cubic<T: Float>(x: T) -> T {
+        x.powi(3) - T::from(2.0).unwrap() * x - T::from(5.0).unwrap()
+    }
+
+    pub fn double_root<T: Float>(x: T) -> T {
+        x.powi(3) - T::from(3.0).unwrap() * x + T::from(2.0).unwrap()
+    }
+}
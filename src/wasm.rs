@@ -0,0 +1,148 @@
+/*!
+WebAssembly bindings, with the `wasm` feature, for running the solvers in a browser.
+
+Functions are given as expression strings, parsed by `expr::Expr`, and methods by the names the command-line interface
+uses, so that a web page can pass through whatever the user types. Results are flat arrays of numbers, which
+`wasm-bindgen` turns into `Float64Array`s. Build with e.g.
+
+```sh
+cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/root_finding.wasm
+```
+
+```js
+import init, { solve, evaluate } from "./pkg/root_finding.js";
+
+await init();
+const res = solve("2*x - 3*sin(x) + 5", "newton", -4.0, -4.0, 0.0, 1e-10, 100);
+console.log(res.root, res.history);
+```
+
+Functions
+---------
+* `solve` : Runs a root-finding method on an expression.
+* `evaluate` : Evaluates an expression at many points, e.g. to plot it.
+* `check_expression` : Checks that an expression parses, returning the parse error if not.
+
+Types
+-----
+* `Solution` : The root, iterates and error estimates of a search, as flat arrays.
+*/
+
+use analysis;
+use clap::ValueEnum;
+use cli::{self, Case, Function, Method};
+use expr::Expr;
+use root_search::Budget;
+use wasm_bindgen::prelude::*;
+
+/// The outcome of `solve`: the root, and the iterates computed on the way to it.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    root: f64,
+    history: Vec<f64>,
+    evaluations: usize,
+    errors: Vec<f64>,
+    order: Option<f64>,
+}
+
+#[wasm_bindgen]
+impl Solution {
+    #[wasm_bindgen(getter)]
+    pub fn root(&self) -> f64 {
+        self.root
+    }
+
+    /// The iterates `x_0, x_1, ...`, or for bisection the midpoints.
+    #[wasm_bindgen(getter)]
+    pub fn history(&self) -> Vec<f64> {
+        self.history.clone()
+    }
+
+    /// The number of function evaluations made.
+    #[wasm_bindgen(getter)]
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// The errors `x_N - root` of the iterates, taking the final root as exact.
+    #[wasm_bindgen(getter)]
+    pub fn errors(&self) -> Vec<f64> {
+        self.errors.clone()
+    }
+
+    /// The estimated order of convergence, as in `analysis::estimate_order`, if there are enough iterates.
+    #[wasm_bindgen(getter)]
+    pub fn order(&self) -> Option<f64> {
+        self.order
+    }
+}
+
+fn parse(expr: &str) -> Result<Expr, JsError> {
+    Expr::parse(expr).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/**
+Runs a root-finding method on an expression in `x`.
+
+`method` is one of the names accepted by `--method` on the command line, e.g. `"bisection"` or `"newton"`. Bisection and
+the hybrid method search `[a, b]`, and the other methods start from `x0`. Derivatives are computed by automatic
+differentiation.
+
+Errors
+------
+* If the expression doesn't parse, the method is unknown, or the search fails.
+*/
+#[wasm_bindgen]
+pub fn solve(
+    expr: &str,
+    method: &str,
+    x0: f64,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, JsError> {
+    let expr: Expr = parse(expr)?;
+    let method: Method = Method::from_str(method, true)
+        .map_err(|_| JsError::new(&format!("Error: unknown method {}!", method)))?;
+    let res = cli::solve(&Case {
+        method,
+        function: Function::Identity,
+        expr: Some(&expr),
+        x0,
+        interval: (a, b),
+        k: 0.0,
+        tol,
+        budget: Budget::new(max_iter, None),
+    })
+    .map_err(|err| JsError::new(&err.to_string()))?;
+    let report = analysis::estimate_order(&res.history, res.root);
+    Ok(Solution {
+        root: res.root,
+        evaluations: res.evaluations,
+        errors: report.errors,
+        order: report.order,
+        history: res.history,
+    })
+}
+
+/**
+Evaluates an expression in `x` at each of `xs`, e.g. to plot it alongside the iterates.
+
+Errors
+------
+* If the expression doesn't parse.
+*/
+#[wasm_bindgen]
+pub fn evaluate(expr: &str, xs: &[f64]) -> Result<Vec<f64>, JsError> {
+    let expr: Expr = parse(expr)?;
+    Ok(xs.iter().map(|&x| expr.eval(x)).collect())
+}
+
+/// Checks that an expression parses, returning the parse error if not, e.g. to validate user input as it is typed.
+#[wasm_bindgen]
+pub fn check_expression(expr: &str) -> Option<String> {
+    Expr::parse(expr).err().map(|err| err.to_string())
+}
@@ -0,0 +1,256 @@
+/*!
+Property-based tests of the bracketing root-finders: on randomly generated functions with known roots, every
+bracketing method must return the root to within the requested tolerance.
+
+The functions are written in terms of `x - r`, where `r` is the root, so that their computed sign is exact and a
+correct bracketing method can always meet its tolerance. The noisy methods are tested on the same functions with
+bounded noise added, and must find the root to within the tolerance or the noise, whichever is larger.
+*/
+
+extern crate proptest;
+extern crate root_finding;
+
+use proptest::prelude::*;
+use root_finding::root_search::{self, Bracket, Budget};
+
+/// The monotone cubic `a(x-r)^3 + b(x-r)`, with `a, b > 0`.
+fn cubic(a: f64, b: f64, r: f64) -> impl Fn(f64) -> f64 {
+    move |x: f64| -> f64 {
+        let d: f64 = x - r;
+        a * d * d * d + b * d
+    }
+}
+
+/// One of several monotone transcendental functions with a single root at `r`.
+fn transcendental(kind: usize, c: f64, r: f64) -> impl Fn(f64) -> f64 {
+    move |x: f64| -> f64 {
+        let d: f64 = x - r;
+        match kind {
+            0 => (c * d).exp() - 1.0,
+            1 => (c * d).atan(),
+            2 => d + 0.9 * d.sin() / c.max(1.0),
+            _ => (c * d).tanh() + d,
+        }
+    }
+}
+
+/// `func` with deterministic noise of up to `noise` added, varying much faster than `func` itself.
+fn noisy<F: Fn(f64) -> f64>(func: F, noise: f64) -> impl Fn(f64) -> f64 {
+    move |x: f64| -> f64 { func(x) + noise * (1e7 * x).sin() }
+}
+
+/// A root `r`, and an interval `(r - left, r + right)` around it.
+fn root_and_domain() -> impl Strategy<Value = (f64, (f64, f64))> {
+    (-10.0..10.0_f64, 0.1..10.0_f64, 0.1..10.0_f64)
+        .prop_map(|(r, left, right)| (r, (r - left, r + right)))
+}
+
+fn tolerance() -> impl Strategy<Value = f64> {
+    (-12..-1_i32).prop_map(|n| 10.0_f64.powi(n))
+}
+
+proptest! {
+    #[test]
+    fn binary_finds_root_of_monotone_cubic(
+        a in 0.1..10.0_f64,
+        b in 0.1..10.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+    ) {
+        let func = cubic(a, b, r);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let found = root_search::binary(&func, bracket, tol, Budget::unlimited()).unwrap();
+        let res = found.result;
+        prop_assert!((res.root - r).abs() <= tol / 2.0, "{} vs {}", res.root, r);
+        prop_assert_eq!(res.history.len(), res.residuals.len());
+        prop_assert!(found.bracket.lo() <= r && r <= found.bracket.hi());
+        prop_assert!(found.bracket.width() <= tol);
+    }
+
+    #[test]
+    fn binary_finds_root_of_transcendental(
+        kind in 0..4_usize,
+        c in 0.1..5.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+    ) {
+        let func = transcendental(kind, c, r);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::binary(&func, bracket, tol, Budget::unlimited()).unwrap().result;
+        prop_assert!((res.root - r).abs() <= tol, "kind {}: {} vs {}", kind, res.root, r);
+    }
+
+    #[test]
+    fn binary_finds_root_in_single_precision(
+        a in 0.1..10.0_f32,
+        b in 0.1..10.0_f32,
+        r in -10.0..10.0_f32,
+        tol in (-5..-1_i32).prop_map(|n| 10.0_f32.powi(n)),
+    ) {
+        let func = move |x: f32| -> f32 {
+            let d: f32 = x - r;
+            a * d * d * d + b * d
+        };
+        let bracket = Bracket::new(&func, r - 1.0, r + 2.0).unwrap();
+        let res = root_search::binary(&func, bracket, tol, Budget::unlimited()).unwrap().result;
+        prop_assert!((res.root - r).abs() <= tol);
+    }
+
+    #[test]
+    fn chandrupatla_finds_root_of_monotone_cubic(
+        a in 0.1..10.0_f64,
+        b in 0.1..10.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+    ) {
+        let func = cubic(a, b, r);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::chandrupatla(&func, bracket, tol, Budget::unlimited()).unwrap();
+        prop_assert!((res.root - r).abs() <= tol + 4.0 * f64::EPSILON * r.abs(), "{} vs {}", res.root, r);
+        prop_assert_eq!(res.history.len(), res.evaluations);
+    }
+
+    #[test]
+    fn chandrupatla_finds_root_of_transcendental(
+        kind in 0..4_usize,
+        c in 0.1..5.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+    ) {
+        let func = transcendental(kind, c, r);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::chandrupatla(&func, bracket, tol, Budget::unlimited()).unwrap();
+        prop_assert!(
+            (res.root - r).abs() <= tol + 4.0 * f64::EPSILON * r.abs(),
+            "kind {}: {} vs {}", kind, res.root, r
+        );
+    }
+
+    #[test]
+    fn binary_extrapolated_stays_in_bracket(
+        kind in 0..4_usize,
+        c in 0.1..5.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+    ) {
+        let func = transcendental(kind, c, r);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::binary_extrapolated(&func, bracket, tol, Budget::unlimited()).unwrap();
+        let extrapolation = res.extrapolation;
+        prop_assert!(
+            (extrapolation.root - r).abs() <= tol,
+            "kind {}: {} vs {}", kind, extrapolation.root, r
+        );
+        prop_assert!(extrapolation.error <= tol / 2.0);
+    }
+
+    #[test]
+    fn binary_noisy_finds_root_to_noise(
+        a in 0.1..10.0_f64,
+        b in 0.1..10.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+        noise in (-10..-4_i32).prop_map(|n| 10.0_f64.powi(n)),
+    ) {
+        // `cubic` has slope at least `b`, so is at least `noise` in size more than `2 * noise / b` from the root.
+        let func = noisy(cubic(a, b, r), noise);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::binary_noisy(&func, bracket, noise, tol, Budget::unlimited()).unwrap();
+        prop_assert!((res.result.root - r).abs() <= tol + 2.0 * noise / b, "{} vs {}", res.result.root, r);
+        prop_assert!(res.accuracy <= tol.max(2.0 * noise / b));
+    }
+
+    #[test]
+    fn chandrupatla_noisy_finds_root_to_noise(
+        a in 0.1..10.0_f64,
+        b in 0.1..10.0_f64,
+        (r, domain) in root_and_domain(),
+        tol in tolerance(),
+        noise in (-10..-4_i32).prop_map(|n| 10.0_f64.powi(n)),
+    ) {
+        let func = noisy(cubic(a, b, r), noise);
+        let bracket = Bracket::new(&func, domain.0, domain.1).unwrap();
+        let res = root_search::chandrupatla_noisy(&func, bracket, noise, tol, Budget::unlimited()).unwrap();
+        prop_assert!(
+            (res.result.root - r).abs() <= tol + 2.0 * noise / b + 4.0 * f64::EPSILON * r.abs(),
+            "{} vs {}", res.result.root, r
+        );
+    }
+
+    #[test]
+    fn find_all_roots_finds_every_root(
+        mut roots in prop::collection::vec(-10.0..10.0_f64, 1..6),
+        tol in tolerance(),
+    ) {
+        // Keep the roots well separated, so that each lies in its own subdivision.
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        roots.dedup_by(|a, b| *a - *b < 0.5);
+        let func = |x: f64| -> f64 { roots.iter().map(|r| x - r).product() };
+
+        let found = root_search::find_all_roots(&func, (-11.0, 11.0), 1000, tol);
+        prop_assert_eq!(roots.len(), found.len());
+        for (res, r) in found.iter().zip(&roots) {
+            prop_assert!((res.root - r).abs() <= tol, "{} vs {}", res.root, r);
+            // Bisection evaluates the function once per midpoint.
+            prop_assert_eq!(res.history.len(), res.evaluations);
+        }
+    }
+
+    #[test]
+    fn bracket_outward_then_binary_finds_root(
+        a in 0.1..10.0_f64,
+        b in 0.1..10.0_f64,
+        r in -10.0..10.0_f64,
+        offset in -20.0..20.0_f64,
+        tol in tolerance(),
+    ) {
+        let func = cubic(a, b, r);
+        let bracket = root_search::bracket_outward(&func, r + offset, 1.6, 100).unwrap();
+        prop_assert!(bracket.lo() <= r && r <= bracket.hi());
+        let res = root_search::binary(&func, bracket, tol, Budget::unlimited()).unwrap().result;
+        prop_assert!((res.root - r).abs() <= tol);
+    }
+}
+
+#[test]
+fn binary_stops_at_adjacent_floats() {
+    // sqrt(2) isn't representable, so no bracket is narrower than 0, and the search must stop at adjacent floats.
+    let func = |x: f64| x * x - 2.0;
+    let bracket = Bracket::new(&func, 1.0, 2.0).unwrap();
+    let res = root_search::binary(&func, bracket, 0.0, Budget::unlimited())
+        .unwrap()
+        .result;
+    assert!((res.root - 2.0_f64.sqrt()).abs() <= f64::EPSILON);
+    assert!(res.history.len() <= f64::MANTISSA_DIGITS as usize);
+}
+
+#[test]
+fn find_all_roots_stops_at_adjacent_floats() {
+    let func = |x: f64| x * x - 2.0;
+    let found = root_search::find_all_roots(&func, (1.0, 2.0), 10, 1e-20);
+    assert_eq!(1, found.len());
+    assert!((found[0].root - 2.0_f64.sqrt()).abs() <= f64::EPSILON);
+}
+
+#[cfg(feature = "interval")]
+mod verified {
+    use super::*;
+    use root_finding::interval::Interval;
+
+    proptest! {
+        #[test]
+        fn binary_verified_encloses_root_of_monotone_cubic(
+            a in 0.1..10.0_f64,
+            b in 0.1..10.0_f64,
+            (r, domain) in root_and_domain(),
+            tol in tolerance(),
+        ) {
+            let func = move |x: Interval| -> Interval {
+                let d: Interval = x - r;
+                a * d.powi(3) + b * d
+            };
+            let res = root_search::binary_verified(&func, domain, tol).unwrap();
+            prop_assert!(res.contains(r), "{} not in {}", r, res);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/*!
+Tests that every solver given the same `Budget` runs for exactly `max_iter` iterations before giving up with
+`TerminationReason::MaxIterations`, counting an iteration as one new iterate, each with its residual.
+*/
+
+extern crate num;
+extern crate root_finding;
+
+use num::Complex;
+use root_finding::root_search::{self, Bracket, Budget, GlobalPhase, RootError, TerminationReason};
+use root_finding::{functional, test_func_derivative, test_function};
+
+const MAX_ITER: usize = 5;
+
+fn assert_ran_max_iter<T>(name: &str, err: RootError<T>) {
+    assert_eq!(TerminationReason::MaxIterations, err.reason, "{}", name);
+    assert_eq!(MAX_ITER, err.residuals.len(), "{}", name);
+}
+
+#[test]
+fn every_solver_runs_max_iter_iterations() {
+    let budget: Budget = Budget::new(MAX_ITER, None);
+    let func = test_function::trig;
+    let deriv = test_func_derivative::trig;
+    let bracket: Bracket = Bracket::new(&func, -4.0, 0.0).unwrap();
+    let newton = functional::x_minus(functional::newton_raphson(func, deriv));
+
+    let results: Vec<(&str, RootError)> = vec![
+        (
+            "binary",
+            root_search::binary(&func, bracket, 0.0, budget).unwrap_err(),
+        ),
+        (
+            "chandrupatla",
+            root_search::chandrupatla(&func, bracket, 0.0, budget).unwrap_err(),
+        ),
+        (
+            "fixed_point",
+            root_search::fixed_point(&newton, -4.0, 0.0, budget).unwrap_err(),
+        ),
+        (
+            "relaxed_fixed_point",
+            root_search::relaxed_fixed_point(
+                &newton,
+                root_search::Relaxation::Fixed(0.5),
+                -4.0,
+                0.0,
+                budget,
+            )
+            .unwrap_err(),
+        ),
+        (
+            "newton_multiplicity",
+            root_search::newton_multiplicity(&func, &deriv, None, -4.0, 0.0, budget).unwrap_err(),
+        ),
+        (
+            "hybrid_newton",
+            root_search::hybrid_newton(
+                &func,
+                &deriv,
+                GlobalPhase::Bisection(bracket),
+                0.0,
+                0.0,
+                budget,
+            )
+            .unwrap_err(),
+        ),
+    ];
+    for (name, err) in results {
+        assert_ran_max_iter(name, err);
+    }
+
+    let square = |z: Complex<f64>| z * z + 1.0;
+    let square_deriv = |z: Complex<f64>| z * 2.0;
+    let err =
+        root_search::newton_complex(&square, &square_deriv, Complex::new(3.0, 2.0), 0.0, budget)
+            .unwrap_err();
+    assert_ran_max_iter("newton_complex", err);
+}
@@ -0,0 +1,112 @@
+/*!
+Tests of the policy for functions defined on only part of the real line, such as `ln(x)` on `(0, inf)`: infinite
+values are allowed at the endpoints of a bracket, where only their sign is used, but every other NaN or infinite value
+stops the search with `TerminationReason::NonFinite`, recording the point at which it was found.
+*/
+
+extern crate root_finding;
+
+use root_finding::functional;
+use root_finding::root_search::{self, Bracket, Budget, TerminationReason};
+
+fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+fn ln_deriv(x: f64) -> f64 {
+    1.0 / x
+}
+
+#[test]
+fn binary_accepts_infinite_endpoint() {
+    let bracket = Bracket::new(&ln, 0.0, 2.0).unwrap();
+    assert_eq!(f64::NEG_INFINITY, bracket.f_lo());
+    let res = root_search::binary(&ln, bracket, 1e-12, Budget::unlimited())
+        .unwrap()
+        .result;
+    assert!((res.root - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn bracket_rejects_nan_endpoint() {
+    assert!(Bracket::new(&ln, -1.0, 2.0).is_err());
+}
+
+#[test]
+fn binary_stops_at_non_finite_midpoint() {
+    // A sign change across a pole, with the pole at the first midpoint.
+    let func = |x: f64| -> f64 { 1.0 / (x - 1.0) };
+    let bracket = Bracket::new(&func, 0.0, 2.0).unwrap();
+    let err = root_search::binary(&func, bracket, 1e-12, Budget::unlimited()).unwrap_err();
+    assert_eq!(TerminationReason::NonFinite, err.reason);
+    assert_eq!(Some(&1.0), err.non_finite_at());
+    assert_eq!(1, err.evaluations);
+}
+
+#[test]
+fn find_all_roots_skips_points_outside_domain() {
+    let found = root_search::find_all_roots(&ln, (-1.0, 2.0), 30, 1e-12);
+    assert_eq!(1, found.len());
+    assert!((found[0].root - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn find_all_roots_skips_non_finite_midpoint() {
+    // The pole at 1 is the midpoint of the sub-interval [0, 2], but the roots on either side are still found.
+    let func = |x: f64| -> f64 { (x + 2.5) * (x - 3.5) / (x - 1.0) };
+    let found = root_search::find_all_roots(&func, (-4.0, 4.0), 4, 1e-12);
+    let roots: Vec<f64> = found.iter().map(|res| res.root).collect();
+    assert_eq!(2, roots.len(), "found {:?}", roots);
+    assert!((roots[0] + 2.5).abs() < 1e-12);
+    assert!((roots[1] - 3.5).abs() < 1e-12);
+}
+
+#[test]
+fn find_all_roots_rejects_poles() {
+    let tan = |x: f64| -> f64 { x.tan() };
+    let found = root_search::find_all_roots(&tan, (-2.0, 5.0), 70, 1e-12);
+    let roots: Vec<f64> = found.iter().map(|res| res.root).collect();
+    assert_eq!(2, roots.len(), "found {:?}", roots);
+    assert!(roots[0].abs() < 1e-12);
+    assert!((roots[1] - std::f64::consts::PI).abs() < 1e-12);
+}
+
+#[test]
+fn fixed_point_stops_when_iterate_leaves_domain() {
+    // From 3, Newton-Raphson on ln(x) overshoots to a negative iterate, where ln(x) is NaN.
+    let newton = functional::x_minus(functional::newton_raphson(ln, ln_deriv));
+    let err = root_search::fixed_point(&newton, 3.0, 1e-12, Budget::new(100, None)).unwrap_err();
+    assert_eq!(TerminationReason::NonFinite, err.reason);
+    let x: f64 = *err.non_finite_at().unwrap();
+    assert!(x < 0.0);
+    assert_eq!(Some(&3.0), err.history.first());
+    assert!(err.to_string().contains(&x.to_string()));
+}
+
+#[test]
+fn fixed_point_converges_within_domain() {
+    let newton = functional::x_minus(functional::newton_raphson(ln, ln_deriv));
+    let res = root_search::fixed_point(&newton, 2.0, 1e-12, Budget::new(100, None)).unwrap();
+    assert!((res.root - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn newton_multiplicity_stops_when_iterate_leaves_domain() {
+    let err = root_search::newton_multiplicity(
+        &ln,
+        &ln_deriv,
+        Some(1.0),
+        3.0,
+        1e-12,
+        Budget::new(100, None),
+    )
+    .unwrap_err();
+    assert_eq!(TerminationReason::NonFinite, err.reason);
+    assert!(*err.non_finite_at().unwrap() < 0.0);
+}
+
+#[test]
+fn bracket_outward_stops_outside_domain() {
+    // From near 0, the starting interval already reaches negative x, where ln(x) is NaN.
+    assert!(root_search::bracket_outward(&ln, 0.001, 1.6, 50).is_err());
+}
@@ -0,0 +1,186 @@
+#![cfg(feature = "ffi")]
+
+extern crate root_finding;
+
+use root_finding::ffi::*;
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::ptr;
+
+unsafe extern "C" fn shifted_cos(x: f64, ctx: *mut c_void) -> f64 {
+    x.cos() - *(ctx as *const f64)
+}
+
+unsafe extern "C" fn shifted_cos_deriv(x: f64, _ctx: *mut c_void) -> f64 {
+    -x.sin()
+}
+
+unsafe extern "C" fn cos(x: f64, _ctx: *mut c_void) -> f64 {
+    x.cos()
+}
+
+unsafe extern "C" fn sqrt(x: f64, _ctx: *mut c_void) -> f64 {
+    x.sqrt() - 1.0
+}
+
+unsafe extern "C" fn pole(x: f64, _ctx: *mut c_void) -> f64 {
+    1.0 / (x - 1.0)
+}
+
+#[test]
+fn bisect_uses_context() {
+    let mut shift: f64 = 0.5;
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let mut bracket = RfBracket { lo: 0.0, hi: 0.0 };
+    let ctx = &mut shift as *mut f64 as *mut c_void;
+    let status = unsafe {
+        rf_bisect(
+            Some(shifted_cos),
+            ctx,
+            0.0,
+            2.0,
+            1e-10,
+            100,
+            &mut bracket,
+            &mut res,
+        )
+    };
+    assert_eq!(RfStatus::Ok, status);
+    assert!((res.root - 0.5f64.acos()).abs() < 1e-9);
+    assert!(res.iterations > 0);
+    assert!(bracket.lo <= 0.5f64.acos() && 0.5f64.acos() <= bracket.hi);
+    assert!(bracket.hi - bracket.lo < 1e-10);
+}
+
+#[test]
+fn bisect_rejects_invalid_bracket() {
+    let mut shift: f64 = 0.5;
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let ctx = &mut shift as *mut f64 as *mut c_void;
+    let status = unsafe {
+        rf_bisect(
+            Some(shifted_cos),
+            ctx,
+            -1.0,
+            1.0,
+            1e-10,
+            100,
+            ptr::null_mut(),
+            &mut res,
+        )
+    };
+    assert_eq!(RfStatus::InvalidBracket, status);
+}
+
+#[test]
+fn bisect_reports_non_finite_midpoint() {
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let status = unsafe {
+        rf_bisect(
+            Some(pole),
+            ptr::null_mut(),
+            0.0,
+            2.0,
+            1e-10,
+            100,
+            ptr::null_mut(),
+            &mut res,
+        )
+    };
+    assert_eq!(RfStatus::NonFinite, status);
+    assert_eq!(1.0, res.root);
+    assert_eq!(1, res.iterations);
+}
+
+#[test]
+fn newton_converges() {
+    let mut shift: f64 = 0.5;
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let ctx = &mut shift as *mut f64 as *mut c_void;
+    let status = unsafe {
+        rf_newton(
+            Some(shifted_cos),
+            Some(shifted_cos_deriv),
+            ctx,
+            1.0,
+            1e-12,
+            100,
+            &mut res,
+        )
+    };
+    assert_eq!(RfStatus::Ok, status);
+    assert!((res.root - 0.5f64.acos()).abs() < 1e-12);
+    assert_eq!(2 * res.iterations, res.evaluations);
+}
+
+#[test]
+fn fixed_point_reports_failure() {
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let status = unsafe { rf_fixed_point(Some(cos), ptr::null_mut(), 1.0, 1e-12, 5, &mut res) };
+    assert_eq!(RfStatus::MaxIterations, status);
+    assert_eq!(5, res.iterations);
+
+    let status = unsafe { rf_fixed_point(Some(sqrt), ptr::null_mut(), -1.0, 1e-12, 5, &mut res) };
+    assert_eq!(RfStatus::NonFinite, status);
+    let message = unsafe { CStr::from_ptr(rf_status_message(status)) };
+    assert_eq!("Error: function is not finite!", message.to_str().unwrap());
+}
+
+#[test]
+fn null_pointers_are_rejected() {
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let status = unsafe { rf_fixed_point(None, ptr::null_mut(), 1.0, 1e-12, 5, &mut res) };
+    assert_eq!(RfStatus::NullPointer, status);
+    let status =
+        unsafe { rf_fixed_point(Some(cos), ptr::null_mut(), 1.0, 1e-12, 5, ptr::null_mut()) };
+    assert_eq!(RfStatus::NullPointer, status);
+}
+
+#[test]
+fn bisect_stops_at_max_iter() {
+    let mut shift: f64 = 0.5;
+    let mut res = RfResult {
+        root: 0.0,
+        iterations: 0,
+        evaluations: 0,
+    };
+    let ctx = &mut shift as *mut f64 as *mut c_void;
+    let status = unsafe {
+        rf_bisect(
+            Some(shifted_cos),
+            ctx,
+            0.0,
+            2.0,
+            0.0,
+            5,
+            ptr::null_mut(),
+            &mut res,
+        )
+    };
+    assert_eq!(RfStatus::MaxIterations, status);
+    assert_eq!(5, res.iterations);
+}
@@ -0,0 +1,62 @@
+/*!
+Tests of `polynomial::all_real_roots` on polynomials whose real roots are known, including those on which
+Newton-Raphson from 0 falls into a cycle, and those with complex roots to be deflated.
+*/
+
+extern crate root_finding;
+
+use root_finding::polynomial::{self, Polynomial};
+
+fn assert_roots(poly: &Polynomial, expected: &[f64], tol: f64) {
+    let roots: Vec<f64> = polynomial::all_real_roots(poly, 1e-12);
+    assert_eq!(expected.len(), roots.len(), "{}: found {:?}", poly, roots);
+    for (root, expected) in roots.iter().zip(expected) {
+        assert!((root - expected).abs() < tol, "{}: found {:?}", poly, roots);
+    }
+}
+
+#[test]
+fn finds_root_where_newton_cycles() {
+    // Newton-Raphson from 0 alternates between 0 and 1.
+    let poly = Polynomial::new(vec![2.0, -2.0, 0.0, 1.0]);
+    assert_roots(&poly, &[-1.7692923542386314], 1e-12);
+}
+
+#[test]
+fn finds_no_roots_of_positive_quadratic() {
+    assert_roots(&Polynomial::new(vec![1.0, 0.0, 1.0]), &[], 0.0);
+}
+
+#[test]
+fn deflates_complex_pair() {
+    // (x^2 + 1)(x - 3)(x + 2)
+    let poly = Polynomial::new(vec![-6.0, -1.0, -5.0, -1.0, 1.0]);
+    assert_roots(&poly, &[-2.0, 3.0], 1e-12);
+}
+
+#[test]
+fn finds_repeated_roots() {
+    assert_roots(
+        &Polynomial::from_roots(&[0.5, 4.0, 4.0]),
+        &[0.5, 4.0, 4.0],
+        1e-6,
+    );
+    assert_roots(
+        &Polynomial::from_roots(&[1.0, 1.0, 1.0]),
+        &[1.0, 1.0, 1.0],
+        1e-4,
+    );
+}
+
+#[test]
+fn finds_wilkinson_roots() {
+    let expected: Vec<f64> = (1..=10).map(f64::from).collect();
+    assert_roots(&Polynomial::from_roots(&expected), &expected, 1e-8);
+}
+
+#[test]
+fn leaves_out_non_finite_roots() {
+    // The leading coefficient is subnormal, so the root near -1e320 overflows to -inf.
+    assert_roots(&Polynomial::new(vec![-2.0, 1.0, 1e-320]), &[2.0], 1e-12);
+    assert_roots(&Polynomial::new(vec![f64::NAN, 1.0]), &[], 0.0);
+}
@@ -0,0 +1,88 @@
+/*!
+Round trips through JSON of the solver configurations and results, which are serialisable with the `serde` feature so
+that experiments can be saved, reloaded and compared.
+*/
+
+#![cfg(feature = "serde")]
+
+extern crate root_finding;
+extern crate serde;
+extern crate serde_json;
+
+use root_finding::analysis::{self, ConvergenceReport};
+use root_finding::functional;
+use root_finding::root_search::{
+    self, Bracket, Budget, GlobalPhase, Relaxation, RestartPolicy, Restarted, RootError,
+    RootResult, TerminationReason,
+};
+use root_finding::test_func_derivative;
+use root_finding::test_function;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+use std::time::Duration;
+
+fn round_trip<V: Serialize + DeserializeOwned + PartialEq + Debug>(value: &V) {
+    let json: String = serde_json::to_string(value).unwrap();
+    assert_eq!(
+        *value,
+        serde_json::from_str::<V>(&json).unwrap(),
+        "{}",
+        json
+    );
+}
+
+#[test]
+fn configs_round_trip() {
+    round_trip(&Relaxation::Fixed(0.5));
+    round_trip(&Relaxation::<f64>::Wegstein);
+    round_trip(&functional::DifferenceScheme::FivePoint);
+    let bracket = Bracket::new(&test_function::trig, -4.0, 0.0).unwrap();
+    round_trip(&bracket);
+    round_trip(&GlobalPhase::Bisection(bracket));
+    round_trip(&RestartPolicy {
+        interval: (-3.0, 3.0),
+        max_restarts: 10,
+        seed: u64::MAX,
+    });
+    round_trip(&Budget::new(100, Some(200)).with_max_duration(Duration::from_millis(1500)));
+}
+
+#[test]
+fn results_round_trip() {
+    let newton = functional::x_minus(functional::newton_raphson(
+        test_function::trig,
+        test_func_derivative::trig,
+    ));
+    let res = root_search::fixed_point(&newton, -4.0, 1e-12, Budget::new(100, None)).unwrap();
+    round_trip(&res);
+    let report: ConvergenceReport = analysis::estimate_order(&res.history, res.root);
+    round_trip(&report);
+
+    let cycling = |x: f64| 1.0 - x;
+    let err: RootError =
+        root_search::fixed_point(&cycling, 0.0, 1e-12, Budget::new(100, None)).unwrap_err();
+    assert_eq!(TerminationReason::Cycling { period: 2 }, err.reason);
+    round_trip(&err);
+}
+
+#[test]
+fn results_without_residuals_load() {
+    let json: &str = r#"{"root": 1.0, "history": [2.0, 1.5], "evaluations": 2}"#;
+    let res: RootResult = serde_json::from_str(json).unwrap();
+    assert!(res.residuals.is_empty());
+}
+
+#[test]
+fn bracket_without_sign_change_is_rejected() {
+    let json: &str = r#"{"lo": 0.0, "hi": 1.0, "f_lo": 1.0, "f_hi": 2.0}"#;
+    assert!(serde_json::from_str::<Bracket>(json).is_err());
+}
+
+#[test]
+fn restarts_without_attempts_have_no_outcome() {
+    let json: &str = r#"{"attempts": []}"#;
+    let res: Restarted = serde_json::from_str(json).unwrap();
+    assert_eq!(None, res.outcome());
+    assert_eq!(0, res.evaluations());
+}